@@ -0,0 +1,309 @@
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::{DMatrix, DVector};
+use fructose::algebra::lattice::Lattice;
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::identity::{One, Zero};
+use fructose::properties::helpers::sign::Signed;
+use std::ops::{Index, IndexMut};
+
+/// square matrix whose nonzero elements lie within `lower` subdiagonals and `upper`
+/// superdiagonals. the band is stored column by column, column `j` holds the rows
+/// `j - upper..=j + lower`, i.e. element `(i, j)` is stored at
+/// `data[j * (lower + upper + 1) + i + upper - j]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandedMatrix<T> {
+    pub data: Vec<T>,
+    pub size: usize,
+    pub lower: usize,
+    pub upper: usize,
+}
+
+impl<T: Default + Copy> BandedMatrix<T> {
+    /// zero matrix of size `size x size` with the given bandwidths
+    pub fn new(size: usize, lower: usize, upper: usize) -> Self {
+        Self {
+            data: vec![T::default(); size * (lower + upper + 1)],
+            size,
+            lower,
+            upper,
+        }
+    }
+}
+
+impl<T> BandedMatrix<T> {
+    #[inline]
+    fn offset(&self, row: usize, col: usize) -> Option<usize> {
+        if row >= self.size || col >= self.size || row + self.upper < col || col + self.lower < row
+        {
+            return None;
+        }
+        Some(col * (self.lower + self.upper + 1) + row + self.upper - col)
+    }
+
+    /// returns the element at `row`, `col` or `None` if it is outside of the band
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.offset(row, col).map(|offset| &self.data[offset])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.offset(row, col)
+            .map(move |offset| &mut self.data[offset])
+    }
+}
+
+impl<T> Index<(usize, usize)> for BandedMatrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        self.get(row, col).unwrap_or_else(|| {
+            panic!(
+                "index ({}, {}) outside of the band of a banded matrix of size {}",
+                row, col, self.size
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for BandedMatrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        let size = self.size;
+        self.get_mut(row, col).unwrap_or_else(|| {
+            panic!(
+                "index ({}, {}) outside of the band of a banded matrix of size {}",
+                row, col, size
+            )
+        })
+    }
+}
+
+impl<T: Default + Copy> BandedMatrix<T> {
+    /// dense copy with zeros outside of the band
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let mut mat = DMatrix::default_with_size((self.size, self.size));
+        for col in 0..self.size {
+            for row in col.saturating_sub(self.upper)..self.size.min(col + self.lower + 1) {
+                mat.data[col][row] = self[(row, col)];
+            }
+        }
+        mat
+    }
+}
+
+impl<T: Scalar + Zero> DMatrix<T> {
+    /// copies the band of a square matrix into banded storage,
+    /// panics if an element outside of the band is nonzero
+    pub fn to_banded(&self, lower: usize, upper: usize) -> BandedMatrix<T> {
+        assert_eq!(
+            self.size.0, self.size.1,
+            "only square matrices can be banded"
+        );
+        let mut banded = BandedMatrix::new(self.size.0, lower, upper);
+        for (col, column) in self.data.iter().enumerate() {
+            for (row, e) in column.iter().enumerate() {
+                match banded.get_mut(row, col) {
+                    Some(band) => *band = *e,
+                    None => assert!(
+                        e.is_zero(),
+                        "element ({}, {}) lies outside of the band but is nonzero",
+                        row,
+                        col
+                    ),
+                }
+            }
+        }
+        banded
+    }
+}
+
+/// row wise working storage of the elimination. row swaps fill in up to `lower` additional
+/// superdiagonals, so row `i` holds the columns `i - lower..=i + upper + lower`
+struct BandedElimination<T> {
+    rows: Vec<T>,
+    width: usize,
+    lower: usize,
+    swaps: usize,
+}
+
+impl<T: Copy> BandedElimination<T> {
+    #[inline]
+    fn at(&self, row: usize, col: usize) -> T {
+        self.rows[row * self.width + col + self.lower - row]
+    }
+
+    #[inline]
+    fn at_mut(&mut self, row: usize, col: usize) -> &mut T {
+        &mut self.rows[row * self.width + col + self.lower - row]
+    }
+}
+
+impl<T: Scalar + ClosedOps + Signed + Lattice + Zero + One> BandedMatrix<T> {
+    /// gaussian elimination with partial pivoting in `O(size * lower * (lower + upper))`,
+    /// applies the same row operations to `b`. returns `None` on a zero pivot
+    fn eliminate(&self, mut b: Option<&mut [T]>) -> Option<BandedElimination<T>> {
+        let (size, lower, upper) = (self.size, self.lower, self.upper);
+        let width = 2 * lower + upper + 1;
+        let mut work = BandedElimination {
+            rows: vec![T::zero(); size * width],
+            width,
+            lower,
+            swaps: 0,
+        };
+        for i in 0..size {
+            for j in i.saturating_sub(lower)..size.min(i + upper + 1) {
+                *work.at_mut(i, j) = self[(i, j)];
+            }
+        }
+
+        for k in 0..size {
+            let last_row = size.min(k + lower + 1);
+            let last_col = size.min(k + lower + upper + 1);
+
+            let mut pivot = k;
+            for i in k + 1..last_row {
+                if work.at(i, k).abs() > work.at(pivot, k).abs() {
+                    pivot = i;
+                }
+            }
+            if work.at(pivot, k).is_zero() {
+                return None;
+            }
+
+            if pivot != k {
+                for j in k..last_col {
+                    let (a, b) = (work.at(k, j), work.at(pivot, j));
+                    *work.at_mut(k, j) = b;
+                    *work.at_mut(pivot, j) = a;
+                }
+                if let Some(b) = b.as_deref_mut() {
+                    b.swap(k, pivot);
+                }
+                work.swaps += 1;
+            }
+
+            for i in k + 1..last_row {
+                let factor = work.at(i, k) / work.at(k, k);
+                if factor.is_zero() {
+                    continue;
+                }
+                for j in k..last_col {
+                    let sub = factor * work.at(k, j);
+                    *work.at_mut(i, j) -= sub;
+                }
+                if let Some(b) = b.as_deref_mut() {
+                    let sub = factor * b[k];
+                    b[i] -= sub;
+                }
+            }
+        }
+
+        Some(work)
+    }
+
+    /// determinant from the banded LU decomposition
+    pub fn determinant(&self) -> T {
+        match self.eliminate(None) {
+            Some(work) => {
+                let mut det = T::one();
+                for k in 0..self.size {
+                    det *= work.at(k, k);
+                }
+                if work.swaps % 2 == 1 {
+                    T::zero() - det
+                } else {
+                    det
+                }
+            }
+            None => T::zero(),
+        }
+    }
+
+    /// solves `self * x = b` with the banded LU decomposition, returns `None` if `self` is
+    /// singular
+    pub fn solve(&self, b: &DVector<T>) -> Option<DVector<T>> {
+        assert_eq!(self.size, b.len);
+        let mut x = b.clone();
+        let work = self.eliminate(Some(&mut x.data))?;
+
+        let bandwidth = self.lower + self.upper + 1;
+        for i in (0..self.size).rev() {
+            for j in i + 1..self.size.min(i + bandwidth) {
+                let sub = work.at(i, j) * x.data[j];
+                x.data[i] -= sub;
+            }
+            x.data[i] /= work.at(i, i);
+        }
+
+        Some(x)
+    }
+}
+
+#[cfg(test)]
+mod banded_tests {
+    use crate::algebra::linear::{BandedMatrix, DMatrix, DVector, SquareMatrix, Vector};
+
+    #[test]
+    fn pentadiagonal() {
+        // small diagonal elements force row swaps
+        let mut banded = BandedMatrix::<f64>::new(6, 2, 2);
+        let mut dense = SquareMatrix::<f64, 6>::default();
+        for i in 0..6usize {
+            for j in i.saturating_sub(2)..6.min(i + 3) {
+                let value = match (i as i32 - j as i32).abs() {
+                    0 => 0.5 + i as f64 * 0.1,
+                    1 => 2.0 - j as f64 * 0.3,
+                    _ => -1.0 + i as f64 * 0.25,
+                };
+                banded[(i, j)] = value;
+                dense[[i, j]] = value;
+            }
+        }
+        assert_eq!(banded.get(0, 3), None);
+        assert_eq!(banded.get(5, 3), Some(&dense[[5, 3]]));
+
+        let b = [1.0, -2.0, 0.5, 3.0, 0.0, -1.5];
+        let x = banded.solve(&DVector::new(b.to_vec())).unwrap();
+        let expected = dense.solve(Vector::from(b)).unwrap();
+        for i in 0..6 {
+            assert!((x.data[i] - expected[i]).abs() < 1e-12);
+        }
+
+        let det = dense.lu().unwrap().determinant();
+        assert!((banded.determinant() - det).abs() < 1e-12 * det.abs());
+    }
+
+    #[test]
+    fn singular() {
+        // tridiagonal with two equal rows
+        let mut banded = BandedMatrix::<f64>::new(3, 1, 1);
+        banded[(0, 0)] = 1.0;
+        banded[(0, 1)] = 2.0;
+        banded[(1, 0)] = 1.0;
+        banded[(1, 1)] = 2.0;
+        banded[(2, 2)] = 4.0;
+        assert_eq!(banded.determinant(), 0.0);
+        assert!(banded.solve(&DVector::new(vec![1.0, 2.0, 3.0])).is_none());
+    }
+
+    #[test]
+    fn dense_round_trip() {
+        let dense = DMatrix::<f64>::from("2 -1 0 0;-1 2 -1 0;0 -1 2 -1;0 0 -1 2");
+        let banded = dense.to_banded(1, 1);
+        assert_eq!(banded.data.len(), 12);
+        assert_eq!(banded[(2, 1)], -1.0);
+        assert_eq!(banded.get(0, 2), None);
+        let round_trip = banded.to_dense();
+        assert_eq!(round_trip.size, dense.size);
+        assert_eq!(round_trip.data, dense.data);
+
+        // a wider band than necessary stores explicit zeros
+        assert_eq!(dense.to_banded(2, 1).to_dense().data, dense.data);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the band")]
+    fn to_banded_out_of_band() {
+        DMatrix::<f64>::from("2 -1 0;-1 2 -1;0.5 -1 2").to_banded(1, 1);
+    }
+}
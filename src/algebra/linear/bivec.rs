@@ -1,6 +1,6 @@
 use crate::algebra::linear::scalar::Scalar;
 use fructose::operators::{
-    Additive, ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedSub, Multiplicative,
+    Additive, ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedOps, ClosedSub, Multiplicative,
 };
 use fructose::properties::general::{Associative, Commutative, Identity, Invertible, Set, Total};
 use fructose::specific::complex::Real;
@@ -261,3 +261,188 @@ impl<T: Scalar + ClosedMul + Invertible<Multiplicative>> Invertible<Multiplicati
         *self = -*self;
     }
 }
+
+/// 3D bivector with the components along the `e12` (xy), `e23` (yz) and `e31` (zx) planes
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Bivector3<T> {
+    pub xy: T,
+    pub yz: T,
+    pub zx: T,
+}
+
+impl<T> Bivector3<T> {
+    pub const fn new(xy: T, yz: T, zx: T) -> Self {
+        Self { xy, yz, zx }
+    }
+}
+
+impl<T: Scalar + ClosedMul + ClosedSub> Bivector3<T> {
+    /// commutator product `(AB - BA) / 2`, the lie bracket of the rotation generators.
+    /// using the duals `a = (yz, zx, xy)` this is `-(a × b)`, so e.g. `[e12, e23] = -e31`
+    pub fn commutator(&self, other: &Self) -> Self {
+        Self {
+            xy: self.zx * other.yz - self.yz * other.zx,
+            yz: self.xy * other.zx - self.zx * other.xy,
+            zx: self.yz * other.xy - self.xy * other.yz,
+        }
+    }
+}
+
+impl<T: Scalar + ClosedOps + Real> Bivector3<T> {
+    #[inline]
+    pub fn magnitude_squared(&self) -> T {
+        self.xy * self.xy + self.yz * self.yz + self.zx * self.zx
+    }
+
+    /// for a rotation generator this is the angle of the rotation
+    #[inline]
+    pub fn magnitude(&self) -> T {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// unit bivector in the same plane, a zero bivector stays zero
+    pub fn normalized(&self) -> Self {
+        let magnitude = self.magnitude();
+        if magnitude.is_zero() {
+            return *self;
+        }
+        Self::new(
+            self.xy / magnitude,
+            self.yz / magnitude,
+            self.zx / magnitude,
+        )
+    }
+}
+
+impl<T: Scalar + ClosedAdd> Add for Bivector3<T> {
+    type Output = Self;
+    #[inline]
+    fn add(mut self, rhs: Bivector3<T>) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: Scalar + ClosedAdd> AddAssign for Bivector3<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Bivector3<T>) {
+        self.xy += rhs.xy;
+        self.yz += rhs.yz;
+        self.zx += rhs.zx;
+    }
+}
+
+impl<T: Scalar + ClosedSub> Sub for Bivector3<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(mut self, rhs: Bivector3<T>) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: Scalar + ClosedSub> SubAssign for Bivector3<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Bivector3<T>) {
+        self.xy -= rhs.xy;
+        self.yz -= rhs.yz;
+        self.zx -= rhs.zx;
+    }
+}
+
+impl<T: Scalar + ClosedMul> Mul<T> for Bivector3<T> {
+    type Output = Self;
+    #[inline]
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+impl<T: Scalar + ClosedMul> MulAssign<T> for Bivector3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.xy *= rhs;
+        self.yz *= rhs;
+        self.zx *= rhs;
+    }
+}
+
+impl<T: Scalar + ClosedDiv> Div<T> for Bivector3<T> {
+    type Output = Self;
+    #[inline]
+    fn div(mut self, rhs: T) -> Self {
+        self /= rhs;
+        self
+    }
+}
+
+impl<T: Scalar + ClosedDiv> DivAssign<T> for Bivector3<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.xy /= rhs;
+        self.yz /= rhs;
+        self.zx /= rhs;
+    }
+}
+
+impl<T: Scalar + ClosedNeg> Neg for Bivector3<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.xy, -self.yz, -self.zx)
+    }
+}
+
+#[cfg(test)]
+mod bivec_tests {
+    use crate::algebra::linear::Bivector3;
+
+    #[test]
+    fn commutator() {
+        let e12 = Bivector3::new(1.0, 0.0, 0.0);
+        let e23 = Bivector3::new(0.0, 1.0, 0.0);
+        let e31 = Bivector3::new(0.0, 0.0, 1.0);
+        assert_eq!(e12.commutator(&e23), Bivector3::new(0.0, 0.0, -1.0));
+        assert_eq!(e23.commutator(&e31), Bivector3::new(-1.0, 0.0, 0.0));
+        assert_eq!(e31.commutator(&e12), Bivector3::new(0.0, -1.0, 0.0));
+        assert_eq!(e23.commutator(&e12), e31);
+        assert_eq!(e12.commutator(&e12), Bivector3::default());
+    }
+
+    #[test]
+    fn magnitude() {
+        let e12 = Bivector3::new(1.0, 0.0, 0.0);
+        assert_eq!(e12.magnitude(), 1.0);
+        assert_eq!(Bivector3::new(0.0, 3.0, -4.0).magnitude(), 5.0);
+
+        assert_eq!(Bivector3::new(2.5, 0.0, 0.0).normalized(), e12);
+        let normalized = Bivector3::new(0.0, 3.0, -4.0).normalized();
+        assert_eq!(normalized, Bivector3::new(0.0, 0.6, -0.8));
+        assert_eq!(normalized.magnitude(), 1.0);
+
+        let zero = Bivector3::<f64>::default();
+        assert_eq!(zero.normalized(), zero);
+    }
+
+    #[test]
+    fn linearity() {
+        let a = Bivector3::new(1.0, -2.0, 0.5);
+        let b = Bivector3::new(0.25, 4.0, -3.0);
+        assert_eq!(a * 2.0, a + a);
+        assert_eq!(a + b, Bivector3::new(1.25, 2.0, -2.5));
+        assert_eq!(a - b, Bivector3::new(0.75, -6.0, 3.5));
+        assert_eq!(a + b - b, a);
+        assert_eq!((a + b) * 2.0, a * 2.0 + b * 2.0);
+        assert_eq!(a * 4.0 / 4.0, a);
+        assert_eq!(a - a, Bivector3::default());
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        c *= 3.0;
+        c /= 3.0;
+        assert_eq!(c, b);
+    }
+}
@@ -1,9 +1,33 @@
 use crate::algebra::linear::scalar::Scalar;
-use fructose::operators::{ClosedAdd, ClosedDiv, ClosedMul, ClosedSub};
+use crate::algebra::linear::Pivoting;
+use fructose::algebra::lattice::Lattice;
+use fructose::operators::{ClosedAdd, ClosedDiv, ClosedMul, ClosedOps, ClosedSub};
+use fructose::properties::helpers::float::Float;
+use fructose::properties::helpers::identity::{One, Zero};
+use fructose::properties::helpers::sign::Signed;
+use fructose::specific::complex::Real;
 use std::fmt::{Debug, Display, Formatter};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Range, Sub, SubAssign,
+};
 use std::str::FromStr;
 
+/// the shapes (rows, columns) of two operands that can't be combined,
+/// vectors have the shape `(len, 1)`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ShapeError {
+    pub lhs: (usize, usize),
+    pub rhs: (usize, usize),
+}
+
+impl Display for ShapeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "mismatched shapes {:?} and {:?}", self.lhs, self.rhs)
+    }
+}
+
+impl std::error::Error for ShapeError {}
+
 #[derive(Debug, Clone)]
 pub struct DVector<T> {
     pub data: Vec<T>,
@@ -25,15 +49,74 @@ impl<T: Default + Copy> DVector<T> {
 }
 
 impl<T: Scalar + ClosedAdd + ClosedMul> DVector<T> {
-    pub fn dot(&self, other: Self) -> T {
+    /// inner product, panics if the lengths differ
+    pub fn dot(&self, other: &Self) -> T {
+        assert_eq!(self.len, other.len);
         let mut sum = T::default();
         for i in 0..self.len {
-            sum += self.data[i];
+            sum += self.data[i] * other.data[i];
         }
         sum
     }
 }
 
+impl<T: Scalar + ClosedOps + Zero + One + Lattice> DVector<T> {
+    /// counts the elements in each of `bins` equal width bins spanning `range`. the bins are
+    /// half open except for the last one, which includes `range.1`. elements outside of
+    /// `range` (and NaN) are dropped
+    pub fn histogram(&self, bins: usize, range: (T, T)) -> Vec<usize> {
+        let (low, high) = range;
+        assert!(low <= high, "histogram range has to be increasing");
+        let mut counts = vec![0; bins];
+        if bins == 0 {
+            return counts;
+        }
+
+        // the inner edges, `low + (high - low) * k / bins` for `k` in `1..bins`
+        let mut count = T::zero();
+        for _ in 0..bins {
+            count += T::one();
+        }
+        let mut k = T::zero();
+        let mut edges = Vec::with_capacity(bins - 1);
+        for _ in 1..bins {
+            k += T::one();
+            edges.push(low + (high - low) * k / count);
+        }
+
+        for e in self.data.iter() {
+            if *e >= low && *e <= high {
+                counts[edges.partition_point(|edge| edge <= e)] += 1;
+            }
+        }
+        counts
+    }
+}
+
+impl<T: Scalar + ClosedAdd + ClosedSub> DVector<T> {
+    fn check_len(&self, rhs: &Self) -> Result<(), ShapeError> {
+        if self.len != rhs.len {
+            return Err(ShapeError {
+                lhs: (self.len, 1),
+                rhs: (rhs.len, 1),
+            });
+        }
+        Ok(())
+    }
+
+    /// element wise sum, `Err` if the lengths differ
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, ShapeError> {
+        self.check_len(rhs)?;
+        Ok(self.clone() + rhs.clone())
+    }
+
+    /// element wise difference, `Err` if the lengths differ
+    pub fn try_sub(&self, rhs: &Self) -> Result<Self, ShapeError> {
+        self.check_len(rhs)?;
+        Ok(self.clone() - rhs.clone())
+    }
+}
+
 impl<T: Default + Copy> Default for DVector<T> {
     fn default() -> Self {
         Self {
@@ -115,6 +198,84 @@ impl<T> DMatrix<T> {
     }
 }
 
+impl<T> DMatrix<T> {
+    /// returns the element at `row`, `col` or `None` if it is out of bounds
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.size.0 {
+            return None;
+        }
+        self.data.get(col).and_then(|col| col.get(row))
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.size.0 {
+            return None;
+        }
+        self.data.get_mut(col).and_then(|col| col.get_mut(row))
+    }
+}
+
+impl<T> Index<(usize, usize)> for DMatrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        let size = self.size;
+        self.get(row, col).unwrap_or_else(|| {
+            panic!(
+                "index ({}, {}) out of bounds for matrix of size {:?}",
+                row, col, size
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for DMatrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        let size = self.size;
+        self.get_mut(row, col).unwrap_or_else(|| {
+            panic!(
+                "index ({}, {}) out of bounds for matrix of size {:?}",
+                row, col, size
+            )
+        })
+    }
+}
+
+impl<T: Scalar + ClosedAdd + ClosedSub + ClosedMul> DMatrix<T> {
+    fn shape_error(&self, rhs: &Self) -> ShapeError {
+        ShapeError {
+            lhs: self.size,
+            rhs: rhs.size,
+        }
+    }
+
+    /// element wise sum, `Err` if the sizes differ
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, ShapeError> {
+        if self.size != rhs.size {
+            return Err(self.shape_error(rhs));
+        }
+        Ok(self.clone() + rhs.clone())
+    }
+
+    /// element wise difference, `Err` if the sizes differ
+    pub fn try_sub(&self, rhs: &Self) -> Result<Self, ShapeError> {
+        if self.size != rhs.size {
+            return Err(self.shape_error(rhs));
+        }
+        Ok(self.clone() - rhs.clone())
+    }
+
+    /// matrix product, `Err` if the columns of `self` don't match the rows of `rhs`
+    pub fn try_mul(&self, rhs: &Self) -> Result<Self, ShapeError> {
+        if self.size.1 != rhs.size.0 {
+            return Err(self.shape_error(rhs));
+        }
+        Ok(self.clone() * rhs.clone())
+    }
+}
+
 impl<T: ToString> DMatrix<T> {
     pub fn to_string_vec(&self) -> DMatrix<String> {
         let data_str: Vec<Vec<String>> = self
@@ -146,6 +307,461 @@ impl<T: Default + Copy> DMatrix<T> {
     }
 }
 
+impl<T: Default + Copy> DMatrix<T> {
+    /// stacks the columns of the matrix on top of each other
+    pub fn vectorize(&self) -> DVector<T> {
+        DVector::new(self.data.iter().flatten().copied().collect())
+    }
+
+    /// inverse of `vectorize`, splits the vector into `cols` columns of length `rows`
+    pub fn unvectorize(vec: &DVector<T>, rows: usize, cols: usize) -> DMatrix<T> {
+        assert_eq!(vec.len, rows * cols);
+        let mut mat = Self::default_with_size((rows, cols));
+        for n in 0..cols {
+            mat.data[n].copy_from_slice(&vec.data[n * rows..(n + 1) * rows]);
+        }
+        mat
+    }
+
+    /// copy of the row `i`, panics if it is out of bounds
+    pub fn row(&self, i: usize) -> DVector<T> {
+        assert!(
+            i < self.size.0,
+            "row {} out of bounds for matrix of size {:?}",
+            i,
+            self.size
+        );
+        DVector::new(self.data.iter().map(|col| col[i]).collect())
+    }
+
+    /// copy of the column `j`, panics if it is out of bounds
+    pub fn col(&self, j: usize) -> DVector<T> {
+        assert!(
+            j < self.size.1,
+            "column {} out of bounds for matrix of size {:?}",
+            j,
+            self.size
+        );
+        DVector::new(self.data[j].clone())
+    }
+
+    pub fn transpose(&self) -> DMatrix<T> {
+        let mut transposed = Self::default_with_size((self.size.1, self.size.0));
+        for (n, col) in self.data.iter().enumerate() {
+            for (m, e) in col.iter().enumerate() {
+                transposed.data[m][n] = *e;
+            }
+        }
+        transposed
+    }
+
+    /// transposes a square matrix in place, panics if the matrix isn't square
+    pub fn transpose_mut(&mut self) {
+        assert_eq!(
+            self.size.0, self.size.1,
+            "only square matrices can be transposed in place"
+        );
+        for n in 1..self.size.1 {
+            let (left, right) = self.data.split_at_mut(n);
+            for (m, col) in left.iter_mut().enumerate() {
+                std::mem::swap(&mut col[n], &mut right[0][m]);
+            }
+        }
+    }
+
+    /// reinterprets the column major elements as a matrix of size `size`,
+    /// panics if the number of elements changes
+    pub fn reshape(&mut self, size: (usize, usize)) {
+        assert_eq!(
+            size.0 * size.1,
+            self.size.0 * self.size.1,
+            "can't reshape a matrix of size {:?} to {:?}",
+            self.size,
+            size
+        );
+        *self = Self::unvectorize(&self.vectorize(), size.0, size.1);
+    }
+
+    /// changes the size in place, elements keep their position. elements outside of the new
+    /// size are dropped and new elements are set to `fill`
+    pub fn resize(&mut self, size: (usize, usize), fill: T) {
+        for col in self.data.iter_mut() {
+            col.resize(size.0, fill);
+        }
+        self.data.resize(size.1, vec![fill; size.0]);
+        self.size = size;
+    }
+
+    /// copy of the matrix in the top left corner of a zero matrix of size `size`
+    fn zero_padded(&self, size: (usize, usize)) -> DMatrix<T> {
+        let mut mat = Self::default_with_size(size);
+        for (col, padded) in self.data.iter().zip(mat.data.iter_mut()) {
+            padded[..col.len()].copy_from_slice(col);
+        }
+        mat
+    }
+
+    /// copy of the submatrix spanned by `rows` and `cols`,
+    /// panics if a range is decreasing or exceeds the size of the matrix
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> DMatrix<T> {
+        assert!(
+            rows.start <= rows.end
+                && rows.end <= self.size.0
+                && cols.start <= cols.end
+                && cols.end <= self.size.1,
+            "slice {:?}, {:?} out of bounds for matrix of size {:?}",
+            rows,
+            cols,
+            self.size
+        );
+        DMatrix {
+            data: self.data[cols.clone()]
+                .iter()
+                .map(|col| col[rows.clone()].to_vec())
+                .collect(),
+            size: (rows.len(), cols.len()),
+        }
+    }
+}
+
+impl<T: Scalar + ClosedMul> DMatrix<T> {
+    /// kronecker product, every element `a_ij` is replaced by the block `a_ij * rhs`
+    pub fn kronecker(&self, rhs: &Self) -> Self {
+        let (p, q) = rhs.size;
+        let mut mat = Self::default_with_size((self.size.0 * p, self.size.1 * q));
+        for n in 0..self.size.1 {
+            for m in 0..self.size.0 {
+                for l in 0..q {
+                    for k in 0..p {
+                        mat.data[n * q + l][m * p + k] = self.data[n][m] * rhs.data[l][k];
+                    }
+                }
+            }
+        }
+        mat
+    }
+}
+
+impl<T: Scalar + ClosedOps + Signed + Lattice + Zero> DMatrix<T> {
+    /// solves `self * x = b` with gaussian elimination and partial pivoting,
+    /// returns `None` if `self` is singular
+    #[inline]
+    pub fn solve(&self, b: &DVector<T>) -> Option<DVector<T>> {
+        self.solve_with_pivoting(b, Pivoting::Partial)
+    }
+
+    /// solves `self * x = b` with gaussian elimination and the given pivoting strategy,
+    /// returns `None` if a zero pivot is encountered
+    pub fn solve_with_pivoting(&self, b: &DVector<T>, pivoting: Pivoting) -> Option<DVector<T>> {
+        let mut workspace = LuWorkspace::new();
+        if !workspace.factor_with_pivoting(self, pivoting) {
+            return None;
+        }
+        Some(workspace.solve(b))
+    }
+
+    /// schur complement `D - C A⁻¹ B` of the block partition `[[A, B], [C, D]]` where `A` is
+    /// the leading `split x split` block. returns `None` if `self` isn't square, `split` is
+    /// out of range or `A` is singular
+    pub fn schur_complement(&self, split: usize) -> Option<DMatrix<T>> {
+        let len = self.size.0;
+        if self.size.1 != len || split == 0 || split >= len {
+            return None;
+        }
+        let rest = len - split;
+
+        let a = DMatrix::new(
+            self.data[..split]
+                .iter()
+                .map(|col| col[..split].to_vec())
+                .collect(),
+        );
+        let mut complement = DMatrix::new(
+            self.data[split..]
+                .iter()
+                .map(|col| col[split..].to_vec())
+                .collect(),
+        );
+        for n in 0..rest {
+            // column n of A⁻¹ B
+            let b = DVector::new(self.data[split + n][..split].to_vec());
+            let x = a.solve(&b)?;
+            for m in 0..rest {
+                for k in 0..split {
+                    let sub = self.data[k][split + m] * x.data[k];
+                    complement.data[n][m] -= sub;
+                }
+            }
+        }
+        Some(complement)
+    }
+}
+
+impl<T: Scalar + ClosedOps + Real + Float> DMatrix<T> {
+    /// softmax of every column, the largest element of a column is subtracted before
+    /// exponentiating so large inputs don't overflow
+    pub fn softmax_cols(&self) -> DMatrix<T> {
+        let data = self
+            .data
+            .iter()
+            .map(|col| {
+                let mut max = T::NEG_INFINITY;
+                for e in col.iter() {
+                    max = max.supremum(e);
+                }
+                let mut col: Vec<T> = col.iter().map(|e| (*e - max).exp()).collect();
+                let mut sum = T::zero();
+                for e in col.iter() {
+                    sum += *e;
+                }
+                for e in col.iter_mut() {
+                    *e /= sum;
+                }
+                col
+            })
+            .collect();
+        DMatrix {
+            data,
+            size: self.size,
+        }
+    }
+}
+
+/// output size of `DMatrix::convolve2d`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConvMode {
+    /// every position where the kernel overlaps the matrix,
+    /// `(rows + k_rows - 1, cols + k_cols - 1)`
+    Full,
+    /// same size as the matrix, the kernel is centered (an even sized kernel reaches one
+    /// element further to the top/left)
+    Same,
+    /// only positions where the kernel lies completely inside the matrix,
+    /// `(rows - k_rows + 1, cols - k_cols + 1)`
+    Valid,
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul> DMatrix<T> {
+    /// 2d cross-correlation of the matrix (as an image) with `kernel`, i.e. the kernel isn't
+    /// flipped like in a mathematical convolution. elements outside the matrix are zero
+    pub fn convolve2d(&self, kernel: &DMatrix<T>, mode: ConvMode) -> DMatrix<T> {
+        let (rows, cols) = self.size;
+        let (k_rows, k_cols) = kernel.size;
+        if k_rows == 0 || k_cols == 0 {
+            return DMatrix::default_with_size(match mode {
+                ConvMode::Same => self.size,
+                _ => (0, 0),
+            });
+        }
+
+        // offset of the output in the coordinates of the full output
+        let (size, offset) = match mode {
+            ConvMode::Full => ((rows + k_rows - 1, cols + k_cols - 1), (0, 0)),
+            ConvMode::Same => ((rows, cols), ((k_rows - 1) / 2, (k_cols - 1) / 2)),
+            ConvMode::Valid => (
+                (
+                    (rows + 1).saturating_sub(k_rows),
+                    (cols + 1).saturating_sub(k_cols),
+                ),
+                (k_rows - 1, k_cols - 1),
+            ),
+        };
+
+        let mut result = DMatrix::default_with_size(size);
+        for j in 0..size.1 {
+            for i in 0..size.0 {
+                // the kernel's top left element lies on (top, left) of the zero padded matrix
+                let (top, left) = (i + offset.0, j + offset.1);
+                let mut sum = T::default();
+                for b in 0..k_cols {
+                    let col = match (left + b).checked_sub(k_cols - 1) {
+                        Some(col) if col < cols => col,
+                        _ => continue,
+                    };
+                    for a in 0..k_rows {
+                        if let Some(row) = (top + a).checked_sub(k_rows - 1) {
+                            if row < rows {
+                                sum += kernel.data[b][a] * self.data[col][row];
+                            }
+                        }
+                    }
+                }
+                result.data[j][i] = sum;
+            }
+        }
+        result
+    }
+}
+
+/// below this size (of the smallest dimension) `DMatrix::mul_strassen` multiplies naively
+const STRASSEN_THRESHOLD: usize = 64;
+
+impl<T: Scalar + ClosedAdd + ClosedSub + ClosedMul> DMatrix<T> {
+    /// matrix product with strassen's algorithm in `O(n^2.81)`. the matrices are split into
+    /// (zero padded) quadrants recursively until a dimension drops below 64, where the naive
+    /// product takes over. floats may round differently than `*`
+    pub fn mul_strassen(&self, other: &Self) -> Self {
+        self.mul_strassen_with_threshold(other, STRASSEN_THRESHOLD)
+    }
+
+    fn mul_strassen_with_threshold(&self, other: &Self, threshold: usize) -> Self {
+        assert_eq!(self.size.1, other.size.0);
+        let (rows, inner, cols) = (self.size.0, self.size.1, other.size.1);
+        if rows.min(inner).min(cols) <= threshold.max(1) {
+            return self.clone() * other.clone();
+        }
+
+        let (m, k, n) = (rows.div_ceil(2), inner.div_ceil(2), cols.div_ceil(2));
+        let a = self.zero_padded((2 * m, 2 * k));
+        let b = other.zero_padded((2 * k, 2 * n));
+        let (a11, a12) = (a.slice(0..m, 0..k), a.slice(0..m, k..2 * k));
+        let (a21, a22) = (a.slice(m..2 * m, 0..k), a.slice(m..2 * m, k..2 * k));
+        let (b11, b12) = (b.slice(0..k, 0..n), b.slice(0..k, n..2 * n));
+        let (b21, b22) = (b.slice(k..2 * k, 0..n), b.slice(k..2 * k, n..2 * n));
+
+        let mul = |lhs: Self, rhs: Self| lhs.mul_strassen_with_threshold(&rhs, threshold);
+        let m1 = mul(a11.clone() + a22.clone(), b11.clone() + b22.clone());
+        let m2 = mul(a21.clone() + a22.clone(), b11.clone());
+        let m3 = mul(a11.clone(), b12.clone() - b22.clone());
+        let m4 = mul(a22.clone(), b21.clone() - b11.clone());
+        let m5 = mul(a11.clone() + a12.clone(), b22.clone());
+        let m6 = mul(a21 - a11, b11 + b12);
+        let m7 = mul(a12 - a22, b21 + b22);
+
+        let c11 = m1.clone() + m4.clone() - m5.clone() + m7;
+        let c12 = m3.clone() + m5;
+        let c21 = m2.clone() + m4;
+        let c22 = m1 - m2 + m3 + m6;
+
+        let mut mat = Self::default_with_size((rows, cols));
+        for j in 0..cols {
+            for i in 0..rows {
+                mat.data[j][i] = match (i < m, j < n) {
+                    (true, true) => c11.data[j][i],
+                    (true, false) => c12.data[j - n][i],
+                    (false, true) => c21.data[j][i - m],
+                    (false, false) => c22.data[j - n][i - m],
+                };
+            }
+        }
+        mat
+    }
+}
+
+/// LU decomposition with row pivoting that keeps its buffers between factorizations,
+/// so repeatedly factoring matrices (of at most the same size) doesn't allocate
+#[derive(Debug, Clone, Default)]
+pub struct LuWorkspace<T> {
+    /// L below the diagonal (with an implicit unit diagonal) and U on and above it,
+    /// stored column by column
+    packed: Vec<T>,
+    /// row `k` was swapped with row `pivots[k]` in step `k`
+    pivots: Vec<usize>,
+    size: usize,
+    factored: bool,
+}
+
+impl<T: Scalar + ClosedOps + Signed + Lattice + Zero> LuWorkspace<T> {
+    pub fn new() -> Self {
+        Self {
+            packed: Vec::new(),
+            pivots: Vec::new(),
+            size: 0,
+            factored: false,
+        }
+    }
+
+    /// factorizes the square matrix `a` into the workspace with partial pivoting,
+    /// returns `false` if it is singular
+    #[inline]
+    pub fn factor(&mut self, a: &DMatrix<T>) -> bool {
+        self.factor_with_pivoting(a, Pivoting::Partial)
+    }
+
+    /// factorizes the square matrix `a` into the workspace with the given pivoting strategy,
+    /// returns `false` if a zero pivot is encountered
+    pub fn factor_with_pivoting(&mut self, a: &DMatrix<T>, pivoting: Pivoting) -> bool {
+        assert_eq!(a.size.0, a.size.1, "can't factor a non-square matrix");
+        let len = a.size.0;
+        self.size = len;
+        self.packed.clear();
+        for col in a.data.iter() {
+            self.packed.extend_from_slice(col);
+        }
+        self.pivots.clear();
+        self.pivots.extend(0..len);
+        self.factored = false;
+
+        let packed = &mut self.packed;
+        for k in 0..len {
+            let mut pivot = k;
+            if pivoting == Pivoting::Partial {
+                for i in k + 1..len {
+                    if packed[k * len + i].abs() > packed[k * len + pivot].abs() {
+                        pivot = i;
+                    }
+                }
+            }
+            if packed[k * len + pivot].is_zero() {
+                return false;
+            }
+
+            if pivot != k {
+                for n in 0..len {
+                    packed.swap(n * len + pivot, n * len + k);
+                }
+            }
+            self.pivots[k] = pivot;
+
+            for i in k + 1..len {
+                let factor = packed[k * len + i] / packed[k * len + k];
+                packed[k * len + i] = factor;
+                for j in k + 1..len {
+                    let sub = factor * packed[j * len + k];
+                    packed[j * len + i] -= sub;
+                }
+            }
+        }
+
+        self.factored = true;
+        true
+    }
+
+    /// solves `A * x = b` for the last factorized matrix `A`, overwriting `b` with `x`.
+    /// panics if the last factorization failed
+    pub fn solve_in_place(&self, b: &mut DVector<T>) {
+        assert!(self.factored, "no successfully factorized matrix");
+        let len = self.size;
+        assert_eq!(len, b.len);
+        let (packed, x) = (&self.packed, &mut b.data);
+
+        for (k, &pivot) in self.pivots.iter().enumerate() {
+            x.swap(k, pivot);
+        }
+        for i in 0..len {
+            for j in 0..i {
+                let sub = packed[j * len + i] * x[j];
+                x[i] -= sub;
+            }
+        }
+        for i in (0..len).rev() {
+            for j in i + 1..len {
+                let sub = packed[j * len + i] * x[j];
+                x[i] -= sub;
+            }
+            x[i] /= packed[i * len + i];
+        }
+    }
+
+    /// solves `A * x = b` for the last factorized matrix `A`,
+    /// panics if the last factorization failed
+    pub fn solve(&self, b: &DVector<T>) -> DVector<T> {
+        let mut x = b.clone();
+        self.solve_in_place(&mut x);
+        x
+    }
+}
+
 impl<T: Scalar + ClosedAdd> Add for DMatrix<T> {
     type Output = Self;
 
@@ -202,8 +818,8 @@ impl<T: Scalar + ClosedAdd + ClosedMul> Mul for DMatrix<T> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        assert_eq!(self.size.0, rhs.size.1);
-        let mut mat = Self::default_with_size((rhs.size.1, self.size.0));
+        assert_eq!(self.size.1, rhs.size.0);
+        let mut mat = Self::default_with_size((self.size.0, rhs.size.1));
         for m in 0..self.size.0 {
             for p in 0..rhs.size.1 {
                 for n in 0..self.size.1 {
@@ -215,16 +831,38 @@ impl<T: Scalar + ClosedAdd + ClosedMul> Mul for DMatrix<T> {
     }
 }
 
-impl<T: Scalar + ClosedMul> MulAssign for DMatrix<T> {
+/// multiplies the corresponding pairs of matrices of both batches
+pub fn batch_matmul<T: Scalar + ClosedAdd + ClosedMul>(
+    batch_a: &[DMatrix<T>],
+    batch_b: &[DMatrix<T>],
+) -> Vec<DMatrix<T>> {
+    assert_eq!(batch_a.len(), batch_b.len(), "batches differ in length");
+    batch_a
+        .iter()
+        .zip(batch_b.iter())
+        .map(|(a, b)| a.clone() * b.clone())
+        .collect()
+}
+
+/// multiplies the corresponding pairs of matrices of both batches in parallel
+#[cfg(feature = "rayon")]
+pub fn batch_matmul_par<T: Scalar + ClosedAdd + ClosedMul + Send + Sync>(
+    batch_a: &[DMatrix<T>],
+    batch_b: &[DMatrix<T>],
+) -> Vec<DMatrix<T>> {
+    use rayon::prelude::*;
+
+    assert_eq!(batch_a.len(), batch_b.len(), "batches differ in length");
+    batch_a
+        .par_iter()
+        .zip(batch_b.par_iter())
+        .map(|(a, b)| a.clone() * b.clone())
+        .collect()
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul> MulAssign for DMatrix<T> {
     fn mul_assign(&mut self, rhs: Self) {
-        assert_eq!(self.size.0, rhs.size.1);
-        for m in 0..self.size.0 {
-            for p in 0..rhs.size.1 {
-                for n in 0..self.size.1 {
-                    self.data[p][m] *= rhs.data[p][n];
-                }
-            }
-        }
+        *self = self.clone() * rhs;
     }
 }
 
@@ -245,7 +883,7 @@ impl<T: Default + Copy> From<DVector<T>> for DMatrix<T> {
         let len = rhs.len;
         DMatrix {
             data: vec![rhs.data],
-            size: (len, 0),
+            size: (len, 1),
         }
     }
 }
@@ -262,13 +900,13 @@ impl<T: Scalar + ClosedMul> Mul<T> for DMatrix<T> {
     type Output = Self;
 
     fn mul(self, rhs: T) -> Self::Output {
-        let mut mat = self.clone();
-        for m in 0..self.size.0 {
-            for n in 0..self.size.1 {
-                mat.data[m][n] *= rhs
+        let mut mat = self;
+        for m in 0..mat.size.0 {
+            for n in 0..mat.size.1 {
+                mat.data[n][m] *= rhs
             }
         }
-        self
+        mat
     }
 }
 
@@ -276,7 +914,7 @@ impl<T: Scalar + ClosedMul> MulAssign<T> for DMatrix<T> {
     fn mul_assign(&mut self, rhs: T) {
         for m in 0..self.size.0 {
             for n in 0..self.size.1 {
-                self.data[m][n] *= rhs
+                self.data[n][m] *= rhs
             }
         }
     }
@@ -286,13 +924,13 @@ impl<T: Scalar + ClosedDiv> Div<T> for DMatrix<T> {
     type Output = Self;
 
     fn div(self, rhs: T) -> Self::Output {
-        let mut mat = self.clone();
-        for m in 0..self.size.0 {
-            for n in 0..self.size.1 {
-                mat.data[m][n] /= rhs
+        let mut mat = self;
+        for m in 0..mat.size.0 {
+            for n in 0..mat.size.1 {
+                mat.data[n][m] /= rhs
             }
         }
-        self
+        mat
     }
 }
 
@@ -300,7 +938,7 @@ impl<T: Scalar + ClosedDiv> DivAssign<T> for DMatrix<T> {
     fn div_assign(&mut self, rhs: T) {
         for m in 0..self.size.0 {
             for n in 0..self.size.1 {
-                self.data[m][n] /= rhs
+                self.data[n][m] /= rhs
             }
         }
     }
@@ -310,23 +948,26 @@ impl<T: Display + Copy> Display for DMatrix<T> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut string = String::new();
-        let biggest = format!("{}", self.data.get(1).unwrap().get(1).unwrap()).len();
+        let biggest = self
+            .data
+            .iter()
+            .flatten()
+            .map(|e| format!("{}", e).len())
+            .max()
+            .unwrap_or(0);
         for m in 0..self.size.0 {
-            string.push_str("|");
+            string.push('|');
             for n in 0..self.size.1 {
+                let element = format!("{}", self.data[n][m]);
+                string.push_str(&element);
                 if n == self.size.1 - 1 {
-                    &string.push_str(&format!("{}", self.data.get(n).unwrap().get(m).unwrap()));
                     break;
                 }
-                let current = format!("{}", self.data.get(n).unwrap().get(m).unwrap()).len();
-
-                string.push_str(&format!("{} ", self.data.get(n).unwrap().get(m).unwrap()));
-
-                for _ in current..biggest + 1 {
+                for _ in element.len()..biggest + 2 {
                     string.push(' ');
                 }
             }
-            &string.push_str("|\n");
+            string.push_str("|\n");
         }
         write!(f, "{}", string)
     }
@@ -394,6 +1035,7 @@ impl<T: FromStr + Default> From<&str> for DMatrix<T> {
 mod dynamic_mat_tests {
     use crate::algebra::linear::dynamic::DMatrix;
     use crate::algebra::linear::dynamic::DVector;
+    use crate::algebra::linear::{ConvMode, LuWorkspace, Pivoting, ShapeError};
 
     #[test]
     fn add() {
@@ -416,6 +1058,125 @@ mod dynamic_mat_tests {
         let mat3 = mat1 * mat2;
     }
 
+    #[test]
+    fn mul_non_square() {
+        // regression test, the product used to assert `self.size.0 == rhs.size.1` and size the
+        // result as `(rhs.size.1, self.size.0)`
+        let a = DMatrix::<i32>::from("1 4;2 5;3 6");
+        let b = DMatrix::<i32>::from("7 9 11;8 10 12");
+        let product = a.clone() * b.clone();
+        assert_eq!(product.size, (2, 2));
+        assert_eq!(product.data, vec![vec![58, 139], vec![64, 154]]);
+
+        let column = DMatrix::<i32>::from("1 -1");
+        let product = b * column;
+        assert_eq!(product.size, (3, 1));
+        assert_eq!(product.data, vec![vec![-1, -1, -1]]);
+    }
+
+    #[test]
+    fn mul_assign() {
+        // used to multiply element-wise instead of forming the matrix product
+        let mut mat = DMatrix::<i32>::from("1 4;2 5;3 6");
+        mat *= DMatrix::<i32>::from("7 9 11;8 10 12");
+        assert_eq!(mat.size, (2, 2));
+        assert_eq!(mat.data, vec![vec![58, 139], vec![64, 154]]);
+
+        let mut square = DMatrix::<i32>::from("1 3;2 4");
+        square *= DMatrix::<i32>::from("0 1;1 0");
+        assert_eq!(square.data, vec![vec![2, 4], vec![1, 3]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_mismatched() {
+        let a = DMatrix::<i32>::from("1 4;2 5;3 6");
+        let _ = a.clone() * a;
+    }
+
+    #[test]
+    fn slice() {
+        let mat = DMatrix::<i32>::from("1 2 3 4;5 6 7 8;9 10 11 12;13 14 15 16");
+        let window = mat.slice(1..3, 2..4);
+        assert_eq!(window.size, (2, 2));
+        assert_eq!(window.data, vec![vec![10, 11], vec![14, 15]]);
+        assert_eq!(mat.slice(0..4, 0..4).data, mat.data);
+        assert_eq!(mat.slice(2..2, 0..3).size, (0, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn slice_out_of_bounds() {
+        let mat = DMatrix::<i32>::from("1 2 3 4;5 6 7 8;9 10 11 12;13 14 15 16");
+        mat.slice(3..5, 0..2);
+    }
+
+    #[test]
+    fn batch_matmul() {
+        let batch_a = vec![
+            DMatrix::<f64>::from("1 4;2 5;3 6"),
+            DMatrix::<f64>::from("0 1;-1 0;2 2"),
+        ];
+        let batch_b = vec![
+            DMatrix::<f64>::from("7 9 11;8 10 12"),
+            DMatrix::<f64>::from("1 0 3;-2 1 1"),
+        ];
+        let batch = super::batch_matmul(&batch_a, &batch_b);
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch[0].size, (2, 2));
+        assert_eq!(batch[0].data, vec![vec![58.0, 139.0], vec![64.0, 154.0]]);
+        for (i, product) in batch.iter().enumerate() {
+            let expected = batch_a[i].clone() * batch_b[i].clone();
+            assert_eq!(product.size, expected.size);
+            assert_eq!(product.data, expected.data);
+        }
+
+        #[cfg(feature = "rayon")]
+        {
+            let parallel = super::batch_matmul_par(&batch_a, &batch_b);
+            for (product, expected) in parallel.iter().zip(batch.iter()) {
+                assert_eq!(product.data, expected.data);
+            }
+        }
+    }
+
+    #[test]
+    fn try_ops() {
+        let a = DMatrix::<i32>::from("1 2;3 4;5 6");
+        let b = DMatrix::<i32>::from("1 0 2;0 1 3");
+        let square = DMatrix::<i32>::from("1 0;0 1");
+        let error = ShapeError {
+            lhs: (2, 3),
+            rhs: (3, 2),
+        };
+        assert_eq!(a.try_add(&b).unwrap_err(), error);
+        assert_eq!(a.try_sub(&b).unwrap_err(), error);
+        assert_eq!(
+            a.try_mul(&square).unwrap_err(),
+            ShapeError {
+                lhs: (2, 3),
+                rhs: (2, 2),
+            }
+        );
+        assert_eq!(
+            a.try_add(&a).unwrap().data,
+            vec![vec![2, 4], vec![6, 8], vec![10, 12]]
+        );
+        assert_eq!(a.try_sub(&a).unwrap().data, vec![vec![0, 0]; 3]);
+        assert_eq!(a.try_mul(&b).unwrap().size, (2, 2));
+
+        let u = DVector::new(vec![1, 2, 3]);
+        let v = DVector::new(vec![1, 2]);
+        let error = ShapeError {
+            lhs: (3, 1),
+            rhs: (2, 1),
+        };
+        assert_eq!(u.try_add(&v).unwrap_err(), error);
+        assert_eq!(u.try_sub(&v).unwrap_err(), error);
+        assert_eq!(u.try_add(&u).unwrap().data, vec![2, 4, 6]);
+        assert_eq!(error.to_string(), "mismatched shapes (3, 1) and (2, 1)");
+    }
+
     #[test]
     fn from_arr() {
         let mat2 = DMatrix::from([[7.0, 9.0, 11.0], [8.0, 10.0, 12.0]]);
@@ -431,4 +1192,381 @@ mod dynamic_mat_tests {
         let vec2 = DVector::<f64>::from("-2.5 3 2");
         let vec = vec1 + vec2;
     }
+
+    #[test]
+    fn index() {
+        let mut mat = DMatrix::<i32>::from("1 2 3;4 5 6");
+        assert_eq!(mat.get(2, 0), Some(&3));
+        assert_eq!(mat.get(0, 1), Some(&4));
+        assert_eq!(mat.get(3, 0), None);
+        assert_eq!(mat.get(0, 2), None);
+        assert_eq!(mat[(1, 1)], 5);
+
+        *mat.get_mut(1, 0).unwrap() = 7;
+        mat[(2, 1)] = 8;
+        assert_eq!(mat.data, vec![vec![1, 7, 3], vec![4, 5, 8]]);
+        assert_eq!(mat.get_mut(0, 5), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "index (3, 0) out of bounds for matrix of size (3, 2)")]
+    fn index_out_of_bounds() {
+        let mat = DMatrix::<i32>::from("1 2 3;4 5 6");
+        let _ = mat[(3, 0)];
+    }
+
+    #[test]
+    fn vectorize() {
+        let mat = DMatrix::<f64>::from("1 2 3;4 5 6");
+        let vec = mat.vectorize();
+        assert_eq!(vec.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let back = DMatrix::unvectorize(&vec, 3, 2);
+        assert_eq!(back.size, mat.size);
+        assert_eq!(back.data, mat.data);
+    }
+
+    #[test]
+    fn row_col() {
+        let mat = DMatrix::<i32>::from("1 4;2 5;3 6");
+        let row = mat.row(1);
+        assert_eq!((row.data, row.len), (vec![4, 5, 6], 3));
+        let col = mat.col(2);
+        assert_eq!((col.data, col.len), (vec![3, 6], 2));
+        assert_eq!(mat.row(0).data, vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 out of bounds")]
+    fn row_out_of_bounds() {
+        DMatrix::<i32>::from("1 4;2 5;3 6").row(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 out of bounds")]
+    fn col_out_of_bounds() {
+        DMatrix::<i32>::from("1 4;2 5;3 6").col(3);
+    }
+
+    #[test]
+    fn transpose() {
+        let mat = DMatrix::<i32>::from("1 4;2 5;3 6");
+        assert_eq!(mat.size, (2, 3));
+        let transposed = mat.transpose();
+        assert_eq!(transposed.size, (3, 2));
+        assert_eq!(transposed.data, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        for row in 0..2 {
+            for col in 0..3 {
+                assert_eq!(transposed[(col, row)], mat[(row, col)]);
+            }
+        }
+        assert_eq!(transposed.transpose().data, mat.data);
+
+        let mut square = DMatrix::<i32>::from("1 2 3;4 5 6;7 8 9");
+        square.transpose_mut();
+        assert_eq!(
+            square.data,
+            vec![vec![1, 4, 7], vec![2, 5, 8], vec![3, 6, 9]]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "only square matrices")]
+    fn transpose_mut_not_square() {
+        DMatrix::<i32>::from("1 4;2 5;3 6").transpose_mut();
+    }
+
+    #[test]
+    fn reshape() {
+        let mut mat = DMatrix::<i32>::from("1 2;3 4;5 6");
+        assert_eq!(mat.size, (2, 3));
+        mat.reshape((3, 2));
+        assert_eq!(mat.size, (3, 2));
+        assert_eq!(mat.data, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(mat[(0, 1)], 4);
+        mat.reshape((6, 1));
+        assert_eq!(mat.data, vec![vec![1, 2, 3, 4, 5, 6]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't reshape")]
+    fn reshape_wrong_size() {
+        DMatrix::<i32>::from("1 2;3 4;5 6").reshape((2, 2));
+    }
+
+    #[test]
+    fn resize() {
+        let mut mat = DMatrix::<i32>::from("1 2;3 4");
+        mat.resize((3, 3), 0);
+        assert_eq!(mat.size, (3, 3));
+        assert_eq!(mat.data, vec![vec![1, 2, 0], vec![3, 4, 0], vec![0, 0, 0]]);
+        mat.resize((1, 2), 0);
+        assert_eq!(mat.size, (1, 2));
+        assert_eq!(mat.data, vec![vec![1], vec![3]]);
+    }
+
+    #[test]
+    fn solve() {
+        let mat = DMatrix::<f64>::from("2 4 -2;1 -6 7;1 0 2");
+        let b = DVector::new(vec![5.0, -2.0, 9.0]);
+        let x = mat.solve(&b).unwrap();
+        assert_eq!(x.data, vec![1.0, 1.0, 2.0]);
+
+        let singular = DMatrix::<f64>::from("1 2;2 4");
+        assert!(singular.solve(&DVector::new(vec![1.0, 1.0])).is_none());
+
+        let needs_pivot = DMatrix::<f64>::from("0 1;1 0");
+        let b = DVector::new(vec![2.0, 3.0]);
+        assert!(needs_pivot
+            .solve_with_pivoting(&b, Pivoting::None)
+            .is_none());
+        assert_eq!(needs_pivot.solve(&b).unwrap().data, vec![3.0, 2.0]);
+    }
+
+    #[test]
+    fn schur_complement() {
+        // A = [[2, 0], [0, 4]], B = [[1, 2], [0, 4]], C = [[2, 0], [1, 2]], D = [[5, 1], [0, 3]]
+        let mat = DMatrix::<f64>::new(vec![
+            vec![2.0, 0.0, 2.0, 1.0],
+            vec![0.0, 4.0, 0.0, 2.0],
+            vec![1.0, 0.0, 5.0, 0.0],
+            vec![2.0, 4.0, 1.0, 3.0],
+        ]);
+        // C A⁻¹ B = [[1, 2], [0.5, 3]]
+        let complement = mat.schur_complement(2).unwrap();
+        assert_eq!(complement.size, (2, 2));
+        assert_eq!(complement.data, vec![vec![4.0, -0.5], vec![-1.0, 0.0]]);
+
+        assert!(mat.schur_complement(0).is_none());
+        assert!(mat.schur_complement(4).is_none());
+        let singular = DMatrix::<f64>::from("1 2 0;2 4 0;0 0 1");
+        assert!(singular.schur_complement(2).is_none());
+    }
+
+    #[test]
+    fn convolve2d() {
+        // rows 1 2 3, 4 5 6, 7 8 9 and a 3x3 box kernel
+        let image = DMatrix::<i32>::from("1 4 7;2 5 8;3 6 9");
+        let kernel = DMatrix::<i32>::from("1 1 1;1 1 1;1 1 1");
+
+        let full = image.convolve2d(&kernel, ConvMode::Full);
+        let expected = DMatrix::<i32>::from(
+            "1 5 12 11 7;3 12 27 24 15;6 21 45 39 24;5 16 33 28 17;3 9 18 15 9",
+        );
+        assert_eq!(full.size, (5, 5));
+        assert_eq!(full.data, expected.data);
+
+        let same = image.convolve2d(&kernel, ConvMode::Same);
+        assert_eq!(same.size, (3, 3));
+        assert_eq!(
+            same.data,
+            DMatrix::<i32>::from("12 27 24;21 45 39;16 33 28").data
+        );
+
+        let valid = image.convolve2d(&kernel, ConvMode::Valid);
+        assert_eq!(valid.size, (1, 1));
+        assert_eq!(valid.data, vec![vec![45]]);
+
+        // the kernel isn't flipped
+        let kernel = DMatrix::<i32>::from("1;10");
+        let valid = image.convolve2d(&kernel, ConvMode::Valid);
+        assert_eq!(valid.size, (3, 2));
+        assert_eq!(valid.data, DMatrix::<i32>::from("21 54 87;32 65 98").data);
+        let large = DMatrix::<i32>::from("1 1 1 1;1 1 1 1;1 1 1 1;1 1 1 1");
+        assert_eq!(image.convolve2d(&large, ConvMode::Valid).size, (0, 0));
+    }
+
+    #[test]
+    fn dot() {
+        let a = DVector::new(vec![1, 2, 3]);
+        let b = DVector::new(vec![4, 5, 6]);
+        assert_eq!(a.dot(&b), 32);
+        assert_eq!(b.dot(&a), 32);
+    }
+
+    #[test]
+    fn scalar_mul_div() {
+        let mat = DMatrix::<f64>::from("1 4;2 5;3 6");
+        assert_eq!(mat.size, (2, 3));
+        let scaled = mat.clone() * 2.0;
+        assert_eq!(scaled.size, (2, 3));
+        assert_eq!(scaled.data, DMatrix::<f64>::from("2 8;4 10;6 12").data);
+        let halved = mat.clone() / 2.0;
+        assert_eq!(halved.data, DMatrix::<f64>::from("0.5 2;1 2.5;1.5 3").data);
+
+        let mut mat = mat;
+        mat *= 2.0;
+        assert_eq!(mat.data, scaled.data);
+        mat /= 4.0;
+        assert_eq!(mat.data, halved.data);
+    }
+
+    #[test]
+    fn histogram() {
+        let vec = DVector::new(vec![
+            0.0,
+            0.5,
+            1.0,
+            2.4,
+            2.5,
+            3.9,
+            4.0,
+            5.0,
+            -0.1,
+            4.2,
+            f64::NAN,
+        ]);
+        assert_eq!(vec.histogram(4, (0.0, 4.0)), vec![2, 1, 2, 2]);
+        assert_eq!(vec.histogram(1, (0.0, 5.0)), vec![9]);
+        assert_eq!(vec.histogram(0, (0.0, 5.0)), Vec::<usize>::new());
+
+        let ints = DVector::new(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        assert_eq!(ints.histogram(3, (1, 10)), vec![3, 3, 4]);
+    }
+
+    #[test]
+    fn display() {
+        let mat = DMatrix::<i32>::from("5");
+        assert_eq!(mat.to_string(), "|5|\n");
+        let column = DMatrix::new(vec![vec![1, 22, 3]]);
+        assert_eq!(column.to_string(), "|1|\n|22|\n|3|\n");
+        let mat = DMatrix::<i32>::from("1 2;30 4");
+        assert_eq!(mat.to_string(), "|1   30|\n|2   4|\n");
+        assert_eq!(DMatrix::<i32>::default().to_string(), "");
+    }
+
+    #[test]
+    fn from_dvector() {
+        let mat = DMatrix::from(DVector::new(vec![1, 2, 3]));
+        assert_eq!(mat.size, (3, 1));
+        assert_eq!(mat[(2, 0)], 3);
+
+        let a = DMatrix::<i32>::from("1 4;2 5;3 6");
+        let product = a.clone() * mat;
+        assert_eq!(product.size, (2, 1));
+        assert_eq!(product.data, vec![vec![14, 32]]);
+        let product = a * DVector::new(vec![1, 2, 3]);
+        assert_eq!(product.data, vec![14, 32]);
+        assert_eq!(product.len, 2);
+    }
+
+    #[test]
+    fn mul_strassen() {
+        let matrix = |rows: usize, cols: usize, seed: i64| {
+            let mut mat = DMatrix::<i64>::default_with_size((rows, cols));
+            for j in 0..cols {
+                for i in 0..rows {
+                    mat[(i, j)] = (i as i64 * 7 + j as i64 * 13 + seed) % 19 - 9;
+                }
+            }
+            mat
+        };
+
+        // odd and non-square sizes need padding on every level
+        let a = matrix(70, 67, 1);
+        let b = matrix(67, 75, 5);
+        let product = a.mul_strassen(&b);
+        let expected = a.clone() * b.clone();
+        assert_eq!(product.size, (70, 75));
+        assert_eq!(product.data, expected.data);
+
+        // recurse down to single elements
+        let a = matrix(7, 5, 2);
+        let b = matrix(5, 6, 3);
+        let product = a.mul_strassen_with_threshold(&b, 1);
+        assert_eq!(product.size, (7, 6));
+        assert_eq!(product.data, (a.clone() * b.clone()).data);
+    }
+
+    #[test]
+    fn mul_strassen_fallback() {
+        // below the threshold the naive product is used
+        let a = DMatrix::<f64>::from("1 4;2 5;3 6");
+        let b = DMatrix::<f64>::from("7 9 11;8 10 12");
+        let product = a.mul_strassen(&b);
+        assert_eq!(product.size, (2, 2));
+        assert_eq!(product.data, (a.clone() * b.clone()).data);
+
+        // a single small dimension makes padding not worth it either
+        let row = DMatrix::<f64>::default_with_size((1, 100));
+        assert_eq!(
+            row.mul_strassen(&DMatrix::default_with_size((100, 80)))
+                .size,
+            (1, 80)
+        );
+    }
+
+    #[test]
+    fn softmax_cols() {
+        let mat = DMatrix::<f64>::from("1 2 3;-1 0 4;1000 1000 999");
+        let softmax = mat.softmax_cols();
+        assert_eq!(softmax.size, (3, 3));
+        for col in softmax.data.iter() {
+            let sum: f64 = col.iter().sum();
+            assert!((sum - 1.0).abs() < 1e-12);
+        }
+        let expected = 3f64.exp() / (1f64.exp() + 2f64.exp() + 3f64.exp());
+        assert!((softmax[(2, 0)] - expected).abs() < 1e-12);
+        assert!((softmax[(0, 2)] - softmax[(1, 2)]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn lu_workspace() {
+        let mut workspace = LuWorkspace::new();
+        let a = DMatrix::<f64>::from("0 1 4;2 -1 1;1 3 0");
+        assert!(workspace.factor(&a));
+        for b in [
+            vec![1.0, 2.0, 3.0],
+            vec![-1.0, 0.5, 0.0],
+            vec![0.0, 0.0, 7.0],
+        ] {
+            let b = DVector::new(b);
+            let x = workspace.solve(&b);
+            let expected = a.solve(&b).unwrap();
+            for (x, expected) in x.data.iter().zip(expected.data.iter()) {
+                assert!((x - expected).abs() < 1e-12);
+            }
+        }
+
+        // reuse the buffers for a smaller and then a singular matrix
+        let a = DMatrix::<f64>::from("4 1;2 3");
+        assert!(workspace.factor(&a));
+        let mut b = DVector::new(vec![6.0, 4.0]);
+        workspace.solve_in_place(&mut b);
+        assert_eq!(b.data, vec![1.0, 1.0]);
+        assert!(!workspace.factor(&DMatrix::<f64>::from("1 2;2 4")));
+
+        // a zero leading entry needs a row swap
+        let a = DMatrix::<f64>::from("0 1;1 0");
+        assert!(!workspace.factor_with_pivoting(&a, Pivoting::None));
+        assert!(workspace.factor_with_pivoting(&a, Pivoting::Partial));
+    }
+
+    #[test]
+    #[should_panic(expected = "no successfully factorized matrix")]
+    fn lu_workspace_singular() {
+        let mut workspace = LuWorkspace::new();
+        workspace.factor(&DMatrix::<f64>::from("1 2;2 4"));
+        workspace.solve(&DVector::new(vec![1.0, 1.0]));
+    }
+
+    #[test]
+    fn kronecker_identity() {
+        // vec(A X B) = (Bᵀ ⊗ A) vec(X)
+        let a = DMatrix::<f64>::from("1 3;2 4");
+        let x = DMatrix::<f64>::from("0 1;-1 2");
+        let b = DMatrix::<f64>::from("5 7;6 8");
+        let b_transposed = DMatrix::<f64>::from("5 6;7 8");
+
+        let lhs = (a.clone() * x.clone() * b).vectorize();
+        let kron = b_transposed.kronecker(&a);
+        let vec_x = x.vectorize();
+        for m in 0..kron.size.0 {
+            let mut sum = 0.0;
+            for n in 0..kron.size.1 {
+                sum += kron.data[n][m] * vec_x.data[n];
+            }
+            assert_eq!(sum, lhs.data[m]);
+        }
+    }
 }
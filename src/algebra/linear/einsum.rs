@@ -0,0 +1,150 @@
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::DMatrix;
+use fructose::operators::{ClosedAdd, ClosedMul};
+
+/// einstein summation over matrices, e.g. `"ij,jk->ik"` (product), `"ij->ji"` (transpose)
+/// or `"ii->"` (trace). every input term has exactly two indices, indices missing from the
+/// output are summed over. a scalar result is a `1x1` matrix and a single output index
+/// yields a column. panics if the spec is malformed or the dimensions don't match
+pub fn einsum<T: Scalar + ClosedAdd + ClosedMul>(
+    spec: &str,
+    operands: &[&DMatrix<T>],
+) -> DMatrix<T> {
+    let (inputs, output) = spec
+        .split_once("->")
+        .unwrap_or_else(|| panic!("einsum spec {:?} is missing \"->\"", spec));
+    let inputs: Vec<Vec<char>> = inputs
+        .split(',')
+        .map(|term| term.trim().chars().collect())
+        .collect();
+    let output: Vec<char> = output.trim().chars().collect();
+    assert_eq!(
+        inputs.len(),
+        operands.len(),
+        "einsum spec {:?} expects {} operands",
+        spec,
+        inputs.len()
+    );
+    assert!(output.len() <= 2, "einsum output has at most two indices");
+
+    // every distinct index with its dimension, output indices first
+    let mut indices: Vec<(char, usize)> = Vec::new();
+    for (term, operand) in inputs.iter().zip(operands.iter()) {
+        assert_eq!(term.len(), 2, "einsum term {:?} needs two indices", term);
+        for (&index, &dim) in term.iter().zip([operand.size.0, operand.size.1].iter()) {
+            assert!(
+                index.is_ascii_alphabetic(),
+                "invalid einsum index {:?}",
+                index
+            );
+            match indices.iter().find(|(i, _)| *i == index) {
+                Some(&(_, known)) => assert_eq!(
+                    known, dim,
+                    "einsum index {:?} has mismatched dimensions",
+                    index
+                ),
+                None => indices.push((index, dim)),
+            }
+        }
+    }
+    for (n, index) in output.iter().enumerate() {
+        assert!(
+            !output[..n].contains(index),
+            "einsum output index {:?} is repeated",
+            index
+        );
+    }
+    let mut ordered: Vec<(char, usize)> = output
+        .iter()
+        .map(|index| {
+            *indices
+                .iter()
+                .find(|(i, _)| i == index)
+                .unwrap_or_else(|| panic!("einsum output index {:?} is not an input", index))
+        })
+        .collect();
+    ordered.extend(indices.iter().filter(|(i, _)| !output.contains(i)));
+
+    let position = |index: char| ordered.iter().position(|(i, _)| *i == index).unwrap();
+    let terms: Vec<[usize; 2]> = inputs
+        .iter()
+        .map(|term| [position(term[0]), position(term[1])])
+        .collect();
+    let rows = if output.is_empty() { 1 } else { ordered[0].1 };
+    let cols = if output.len() == 2 { ordered[1].1 } else { 1 };
+    let mut result = DMatrix::default_with_size((rows, cols));
+
+    if ordered.iter().any(|(_, dim)| *dim == 0) {
+        return result;
+    }
+    let mut counter = vec![0; ordered.len()];
+    loop {
+        let mut product = operands[0].data[counter[terms[0][1]]][counter[terms[0][0]]];
+        for (term, operand) in terms.iter().zip(operands.iter()).skip(1) {
+            product *= operand.data[counter[term[1]]][counter[term[0]]];
+        }
+        let row = if output.is_empty() { 0 } else { counter[0] };
+        let col = if output.len() == 2 { counter[1] } else { 0 };
+        result.data[col][row] += product;
+
+        // advance the counter like an odometer
+        let mut digit = ordered.len();
+        loop {
+            if digit == 0 {
+                return result;
+            }
+            digit -= 1;
+            counter[digit] += 1;
+            if counter[digit] < ordered[digit].1 {
+                break;
+            }
+            counter[digit] = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod einsum_tests {
+    use crate::algebra::linear::{einsum, DMatrix};
+
+    #[test]
+    fn matmul() {
+        let a = DMatrix::<i32>::from("1 4;2 5;3 6");
+        let b = DMatrix::<i32>::from("7 9 11;8 10 12");
+        let product = einsum("ij,jk->ik", &[&a, &b]);
+        let expected = a.clone() * b.clone();
+        assert_eq!(product.size, expected.size);
+        assert_eq!(product.data, expected.data);
+    }
+
+    #[test]
+    fn transpose() {
+        let a = DMatrix::<i32>::from("1 4;2 5;3 6");
+        let transposed = einsum("ij->ji", &[&a]);
+        assert_eq!(transposed.size, (3, 2));
+        assert_eq!(transposed.data, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    fn trace() {
+        let a = DMatrix::<i32>::from("1 2 3;4 5 6;7 8 9");
+        let trace = einsum("ii->", &[&a]);
+        assert_eq!(trace.size, (1, 1));
+        assert_eq!(trace.data, vec![vec![15]]);
+        assert_eq!(einsum("ii->i", &[&a]).data, vec![vec![1, 5, 9]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "mismatched dimensions")]
+    fn mismatched_dimensions() {
+        let a = DMatrix::<i32>::from("1 4;2 5;3 6");
+        einsum("ij,jk->ik", &[&a, &a]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expects 2 operands")]
+    fn wrong_operand_count() {
+        let a = DMatrix::<i32>::from("1 4;2 5;3 6");
+        einsum("ij,jk->ik", &[&a]);
+    }
+}
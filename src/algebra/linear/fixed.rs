@@ -0,0 +1,235 @@
+use crate::algebra::linear::{DeterminantAlgorithm, SquareMatrix};
+use fructose::operators::{Additive, Multiplicative};
+use fructose::properties::general::{Identity, Set};
+use std::fmt::{Display, Formatter};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// signed fixed-point number with `BITS` fractional bits stored in an `i64`.
+/// all arithmetic is integer arithmetic, so results are bit identical on every platform,
+/// products and quotients are rounded towards negative infinity. `BITS` has to be below 63
+#[repr(transparent)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const BITS: usize> {
+    raw: i64,
+}
+
+impl<const BITS: usize> Fixed<BITS> {
+    pub const ONE: Self = Self { raw: 1 << BITS };
+
+    /// the fixed-point number with the underlying representation `raw`, i.e. `raw / 2^BITS`
+    #[inline]
+    pub const fn from_raw(raw: i64) -> Self {
+        Self { raw }
+    }
+
+    #[inline]
+    pub const fn raw(&self) -> i64 {
+        self.raw
+    }
+
+    #[inline]
+    pub const fn from_int(value: i64) -> Self {
+        Self { raw: value << BITS }
+    }
+
+    /// nearest representable number, saturates if `value` is out of range
+    #[inline]
+    pub fn from_f64(value: f64) -> Self {
+        Self {
+            raw: (value * (1u64 << BITS) as f64).round() as i64,
+        }
+    }
+
+    #[inline]
+    pub fn to_f64(&self) -> f64 {
+        self.raw as f64 / (1u64 << BITS) as f64
+    }
+}
+
+impl<const BITS: usize> From<f64> for Fixed<BITS> {
+    fn from(value: f64) -> Self {
+        Self::from_f64(value)
+    }
+}
+
+impl<const BITS: usize> From<Fixed<BITS>> for f64 {
+    fn from(value: Fixed<BITS>) -> Self {
+        value.to_f64()
+    }
+}
+
+impl<const BITS: usize> Display for Fixed<BITS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_f64())
+    }
+}
+
+impl<const BITS: usize> Add for Fixed<BITS> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_raw(self.raw + rhs.raw)
+    }
+}
+
+impl<const BITS: usize> Sub for Fixed<BITS> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_raw(self.raw - rhs.raw)
+    }
+}
+
+impl<const BITS: usize> Mul for Fixed<BITS> {
+    type Output = Self;
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_raw(((self.raw as i128 * rhs.raw as i128) >> BITS) as i64)
+    }
+}
+
+impl<const BITS: usize> Div for Fixed<BITS> {
+    type Output = Self;
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        let (lhs, rhs) = ((self.raw as i128) << BITS, rhs.raw as i128);
+        let mut quotient = lhs / rhs;
+        if lhs % rhs != 0 && (lhs < 0) != (rhs < 0) {
+            quotient -= 1;
+        }
+        Self::from_raw(quotient as i64)
+    }
+}
+
+impl<const BITS: usize> Rem for Fixed<BITS> {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self::from_raw(self.raw % rhs.raw)
+    }
+}
+
+impl<const BITS: usize> Neg for Fixed<BITS> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::from_raw(-self.raw)
+    }
+}
+
+macro_rules! impl_assign {
+    ($($trait:ident $fn:ident $op:tt)*) => {
+        $(
+            impl<const BITS: usize> $trait for Fixed<BITS> {
+                #[inline]
+                fn $fn(&mut self, rhs: Self) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+impl_assign!(
+    AddAssign add_assign +
+    SubAssign sub_assign -
+    MulAssign mul_assign *
+    DivAssign div_assign /
+    RemAssign rem_assign %
+);
+
+impl<const BITS: usize> Set<Additive> for Fixed<BITS> {
+    fn operate(&self, rhs: Self) -> Self {
+        *self + rhs
+    }
+}
+
+impl<const BITS: usize> Identity<Additive> for Fixed<BITS> {
+    fn identity() -> Self {
+        Self::from_raw(0)
+    }
+
+    fn is_identity(&self) -> bool {
+        self.raw == 0
+    }
+}
+
+impl<const BITS: usize> Set<Multiplicative> for Fixed<BITS> {
+    fn operate(&self, rhs: Self) -> Self {
+        *self * rhs
+    }
+}
+
+impl<const BITS: usize> Identity<Multiplicative> for Fixed<BITS> {
+    fn identity() -> Self {
+        Self::ONE
+    }
+
+    fn is_identity(&self) -> bool {
+        *self == Self::ONE
+    }
+}
+
+/// the laplace expansion doesn't divide, so only the products are rounded
+impl<const BITS: usize> DeterminantAlgorithm for Fixed<BITS> {
+    #[inline]
+    fn determinant<const M: usize>(mat: &SquareMatrix<Self, { M }>) -> Self {
+        mat.determinant_laplace()
+    }
+}
+
+#[cfg(test)]
+mod fixed_tests {
+    use crate::algebra::linear::{Fixed, Matrix, SquareMatrix};
+
+    type F16 = Fixed<16>;
+
+    #[test]
+    fn arithmetic() {
+        let a = F16::from_f64(1.5);
+        let b = F16::from_f64(-0.25);
+        assert_eq!(a + b, F16::from_f64(1.25));
+        assert_eq!(a - b, F16::from_f64(1.75));
+        assert_eq!(a * b, F16::from_f64(-0.375));
+        assert_eq!(a / b, F16::from_int(-6));
+        assert_eq!(-a, F16::from_f64(-1.5));
+        // rounded towards negative infinity
+        assert_eq!(F16::from_raw(-1) * F16::from_f64(0.5), F16::from_raw(-1));
+        assert_eq!(F16::from_int(1) / F16::from_int(3), F16::from_raw(21845));
+        assert_eq!(F16::from_int(1) / F16::from_int(-3), F16::from_raw(-21846));
+    }
+
+    #[test]
+    fn matrix_mul_deterministic() {
+        let a = SquareMatrix::<f64, 2>::new([[0.1, -2.7], [3.3, 0.45]]).map_into(F16::from_f64);
+        let b = SquareMatrix::<f64, 2>::new([[1.2, 0.3], [-0.7, 2.5]]).map_into(F16::from_f64);
+        let product = (a * b).map_into(|e| e.raw());
+        // the raw values only depend on integer arithmetic
+        assert_eq!(product, Matrix::new([[72745, -203489], [536084, 197589]]));
+
+        let expected = SquareMatrix::<f64, 2>::new([[0.1, -2.7], [3.3, 0.45]])
+            * SquareMatrix::new([[1.2, 0.3], [-0.7, 2.5]]);
+        let approximated = (a * b).map_into(|e| e.to_f64());
+        for m in 0..2 {
+            for n in 0..2 {
+                assert!((approximated[[m, n]] - expected[[m, n]]).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn f64_round_trip() {
+        let mat = Matrix::<f64, 2, 3>::new([[0.5, -1.25], [3.3, 100.0], [-0.001, 7.0]]);
+        let fixed = mat.map_into(F16::from);
+        let back = fixed.map_into(f64::from);
+        for m in 0..2 {
+            for n in 0..3 {
+                assert!((back[[m, n]] - mat[[m, n]]).abs() <= 0.5 / 65536.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::{DMatrix, Matrix, SquareMatrix};
+use fructose::algebra::lattice::Lattice;
+use fructose::operators::{ClosedNeg, ClosedOps};
+use fructose::properties::helpers::identity::{One, Zero};
+use fructose::properties::helpers::sign::Signed;
+
+fn identity<T: Scalar + Zero + One>(len: usize) -> DMatrix<T> {
+    let mut mat = DMatrix::default_with_size((len, len));
+    for i in 0..len {
+        mat.data[i][i] = T::one();
+    }
+    mat
+}
+
+/// solves the sylvester equation `A X + X B = C` for `X`, using the vectorized form
+/// `(I ⊗ A + Bᵀ ⊗ I) vec(X) = vec(C)`. returns `None` if there is no unique solution
+pub fn solve_sylvester<T, const M: usize, const N: usize>(
+    a: &SquareMatrix<T, { M }>,
+    b: &SquareMatrix<T, { N }>,
+    c: &Matrix<T, { M }, { N }>,
+) -> Option<Matrix<T, { M }, { N }>>
+where
+    T: Scalar + ClosedOps + Signed + Lattice + Zero + One,
+{
+    let system = identity(N).kronecker(&DMatrix::from(a.data))
+        + DMatrix::from(b.transpose().data).kronecker(&identity(M));
+    let x = system.solve(&c.vectorize())?;
+
+    let mut solution = Matrix::default();
+    solution.as_slice_mut().copy_from_slice(&x.data);
+    Some(solution)
+}
+
+/// solves the continuous lyapunov equation `A X + X Aᵀ + Q = 0` for `X`,
+/// returns `None` if there is no unique solution
+pub fn solve_lyapunov<T, const N: usize>(
+    a: &SquareMatrix<T, { N }>,
+    q: &SquareMatrix<T, { N }>,
+) -> Option<SquareMatrix<T, { N }>>
+where
+    T: Scalar + ClosedOps + ClosedNeg + Signed + Lattice + Zero + One,
+{
+    solve_sylvester(a, &a.transpose(), &-*q)
+}
+
+#[cfg(test)]
+mod equations_tests {
+    use crate::algebra::linear::{solve_lyapunov, solve_sylvester, Matrix, SquareMatrix};
+
+    #[test]
+    fn lyapunov() {
+        // a = |-1  1|, q = identity
+        //     | 0 -2|
+        let a = SquareMatrix::<f64, 2>::new([[-1.0, 0.0], [1.0, -2.0]]);
+        let q = SquareMatrix::new([[1.0, 0.0], [0.0, 1.0]]);
+        let x = solve_lyapunov(&a, &q).unwrap();
+
+        let expected = SquareMatrix::new([[7.0 / 12.0, 1.0 / 12.0], [1.0 / 12.0, 0.25]]);
+        for m in 0..2 {
+            for n in 0..2 {
+                assert!((x[[m, n]] - expected[[m, n]]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn sylvester() {
+        let a = SquareMatrix::new([[1.0, 0.0], [2.0, 3.0]]);
+        let b = SquareMatrix::new([[4.0, 1.0, 0.0], [0.0, 5.0, 0.0], [0.0, 1.0, 6.0]]);
+        let expected = Matrix::<f64, 2, 3>::new([[1.0, -1.0], [0.5, 2.0], [0.0, 3.0]]);
+        let c = a * expected + expected * b;
+
+        let x = solve_sylvester(&a, &b, &c).unwrap();
+        for m in 0..2 {
+            for n in 0..3 {
+                assert!((x[[m, n]] - expected[[m, n]]).abs() < 1e-12);
+            }
+        }
+    }
+}
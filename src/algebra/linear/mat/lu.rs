@@ -0,0 +1,740 @@
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::{SquareMatrix, Vector};
+use fructose::algebra::lattice::Lattice;
+use fructose::operators::mul_add::MulAdd;
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::float::Float;
+use fructose::properties::helpers::identity::{One, Zero};
+use fructose::properties::helpers::sign::Signed;
+use fructose::specific::complex::Real;
+
+/// row pivoting strategy used during elimination
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum Pivoting {
+    /// no row swaps, faster but fails on a zero pivot and is less stable
+    None,
+    /// swaps in the row with the largest absolute value in the current column
+    #[default]
+    Partial,
+}
+
+/// sign of a determinant, e.g. the orientation of a simplex
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Sign {
+    Negative,
+    Zero,
+    Positive,
+}
+
+/// LU decomposition with row pivoting, `P * A = L * U`. the elements are only cloned,
+/// so it also works for scalars that aren't `Copy` (e.g. arbitrary precision numbers)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Lu<T, const N: usize> {
+    /// L below the diagonal (with an implicit unit diagonal) and U on and above it
+    pub packed: SquareMatrix<T, { N }>,
+    /// row `i` of the factorized matrix is row `pivots[i]` of the original one
+    pub pivots: [usize; N],
+    /// number of row swaps done while pivoting
+    pub swaps: usize,
+}
+
+/// gaussian elimination shared by `Lu` and `lu_in_place`, overwrites `packed` with L below the
+/// diagonal and U on and above it. returns the row permutation and the number of row swaps.
+/// a column without a nonzero pivot either stops the elimination with `None` or is skipped
+/// when `skip_zero_pivots` is set
+fn eliminate<T: Clone + ClosedOps + Signed + Lattice + Zero, const N: usize>(
+    packed: &mut SquareMatrix<T, { N }>,
+    pivoting: Pivoting,
+    skip_zero_pivots: bool,
+) -> Option<([usize; N], usize)> {
+    let mut pivots = [0; N];
+    for (i, pivot) in pivots.iter_mut().enumerate() {
+        *pivot = i;
+    }
+    let mut swaps = 0;
+
+    for k in 0..N {
+        let mut pivot = k;
+        if pivoting == Pivoting::Partial {
+            for i in k + 1..N {
+                if packed[[i, k]].abs() > packed[[pivot, k]].abs() {
+                    pivot = i;
+                }
+            }
+        }
+
+        if packed[[pivot, k]].is_zero() {
+            if skip_zero_pivots {
+                continue;
+            }
+            return None;
+        }
+
+        if pivot != k {
+            for n in 0..N {
+                packed.data[n].swap(pivot, k);
+            }
+            pivots.swap(pivot, k);
+            swaps += 1;
+        }
+
+        for i in k + 1..N {
+            let factor = packed[[i, k]].clone() / packed[[k, k]].clone();
+            for j in k + 1..N {
+                let sub = factor.clone() * packed[[k, j]].clone();
+                packed[[i, j]] -= sub;
+            }
+            packed[[i, k]] = factor;
+        }
+    }
+
+    Some((pivots, swaps))
+}
+
+impl<T: Clone + Default + ClosedOps + Signed + Lattice + Zero + One, const N: usize> Lu<T, { N }> {
+    /// factorizes `mat` with partial pivoting, returns `None` if it is singular
+    #[inline]
+    pub fn new(mat: &SquareMatrix<T, { N }>) -> Option<Self> {
+        Self::new_with(mat, Pivoting::Partial)
+    }
+
+    /// factorizes `mat`, returns `None` if a zero pivot is encountered. without pivoting
+    /// this can also happen for regular matrices
+    pub fn new_with(mat: &SquareMatrix<T, { N }>, pivoting: Pivoting) -> Option<Self> {
+        let mut packed = mat.clone();
+        let (pivots, swaps) = eliminate(&mut packed, pivoting, false)?;
+        Some(Self {
+            packed,
+            pivots,
+            swaps,
+        })
+    }
+
+    /// determinant of the factorized matrix, the product of the diagonal of `U`
+    /// negated for an odd number of row swaps
+    pub fn determinant(&self) -> T {
+        let mut det = T::one();
+        for n in 0..N {
+            det *= self.packed[[n, n]].clone();
+        }
+        if self.swaps % 2 == 1 {
+            T::zero() - det
+        } else {
+            det
+        }
+    }
+
+    /// solves `A * x = b` for the factorized matrix `A`
+    pub fn solve(&self, b: Vector<T, { N }>) -> Vector<T, { N }> {
+        let mut x = Vector::default();
+        for i in 0..N {
+            x[i] = b[self.pivots[i]].clone();
+        }
+
+        for i in 0..N {
+            for j in 0..i {
+                let sub = self.packed[[i, j]].clone() * x[j].clone();
+                x[i] -= sub;
+            }
+        }
+
+        for i in (0..N).rev() {
+            for j in i + 1..N {
+                let sub = self.packed[[i, j]].clone() * x[j].clone();
+                x[i] -= sub;
+            }
+            x[i] /= self.packed[[i, i]].clone();
+        }
+
+        x
+    }
+
+    /// inverse of the factorized matrix, solving for every column of the identity
+    pub fn inverse(&self) -> SquareMatrix<T, { N }> {
+        let mut inverse = SquareMatrix::default();
+        for n in 0..N {
+            let mut unit = Vector::default();
+            unit[n] = T::one();
+            let [column] = self.solve(unit).data;
+            inverse.data[n] = column;
+        }
+        inverse
+    }
+}
+
+impl<T: Clone + Default + ClosedOps + Signed + Lattice + Zero + One, const N: usize>
+    SquareMatrix<T, { N }>
+{
+    #[inline]
+    pub fn lu(&self) -> Option<Lu<T, { N }>> {
+        Lu::new(self)
+    }
+
+    #[inline]
+    pub fn lu_with(&self, pivoting: Pivoting) -> Option<Lu<T, { N }>> {
+        Lu::new_with(self, pivoting)
+    }
+
+    /// overwrites `self` with its LU decomposition with partial pivoting, L below the
+    /// diagonal (with an implicit unit diagonal) and U on and above it. returns the
+    /// permutation, row `i` of `L * U` is row `perm[i]` of the original matrix. a column
+    /// without a nonzero pivot is skipped, leaving a zero on the diagonal of U
+    pub fn lu_in_place(&mut self) -> [usize; N] {
+        let (perm, _) = eliminate(self, Pivoting::Partial, true)
+            .expect("zero pivots are skipped, so the elimination can't fail");
+        perm
+    }
+
+    /// inverse via the LU decomposition, returns `None` if `self` is singular
+    #[inline]
+    pub fn inverse_lu(&self) -> Option<Self> {
+        self.lu().map(|lu| lu.inverse())
+    }
+
+    /// solves `self * x = b`, returns `None` if `self` is singular
+    #[inline]
+    pub fn solve(&self, b: Vector<T, { N }>) -> Option<Vector<T, { N }>> {
+        self.lu().map(|lu| lu.solve(b))
+    }
+
+    /// solves `self * x = b` with the given pivoting strategy,
+    /// returns `None` if a zero pivot is encountered
+    #[inline]
+    pub fn solve_with_pivoting(
+        &self,
+        b: Vector<T, { N }>,
+        pivoting: Pivoting,
+    ) -> Option<Vector<T, { N }>> {
+        self.lu_with(pivoting).map(|lu| lu.solve(b))
+    }
+}
+
+impl<T: Scalar + ClosedOps + Signed + Lattice + Zero + One + Float, const N: usize>
+    SquareMatrix<T, { N }>
+{
+    /// determinant via the LU decomposition with partial pivoting in `O(N^3)`, `0` if singular.
+    /// unlike `determinant` this rounds, but it stays stable for large floating point matrices
+    #[inline]
+    pub fn determinant_lu(&self) -> T {
+        self.lu().map_or(T::zero(), |lu| lu.determinant())
+    }
+}
+
+impl<T: Scalar + ClosedOps + Signed + Lattice + Zero + One, const N: usize> SquareMatrix<T, { N }> {
+    /// LU determinant of the row equilibrated matrix (every row scaled to a largest absolute
+    /// value of one) multiplied back by the row scales. the equilibration lets partial pivoting
+    /// pick pivots by relative instead of absolute size, which helps for badly scaled rows
+    pub fn determinant_equilibrated(&self) -> T {
+        let mut mat = *self;
+        let mut scale = T::one();
+        for m in 0..N {
+            let mut largest = T::zero();
+            for n in 0..N {
+                if mat[[m, n]].abs() > largest {
+                    largest = mat[[m, n]].abs();
+                }
+            }
+            if largest.is_zero() {
+                return T::zero();
+            }
+            for n in 0..N {
+                mat[[m, n]] /= largest;
+            }
+            scale *= largest;
+        }
+        mat.lu().map_or(T::zero(), |lu| lu.determinant() * scale)
+    }
+}
+
+impl<
+        T: Scalar + ClosedOps + Signed + Lattice + Zero + One + MulAdd<Output = T>,
+        const N: usize,
+    > SquareMatrix<T, { N }>
+{
+    /// solves `self * x = b` followed by `iterations` steps of iterative refinement.
+    /// the residual `b - self * x` is accumulated with error-free transformations
+    /// (compensated dot product) so it is computed in roughly twice the working precision
+    pub fn solve_refined(
+        &self,
+        b: Vector<T, { N }>,
+        iterations: usize,
+    ) -> Option<Vector<T, { N }>> {
+        let lu = self.lu()?;
+        let mut x = lu.solve(b);
+        for _ in 0..iterations {
+            let mut residual = b;
+            for i in 0..N {
+                let mut sum = b[i];
+                let mut compensation = T::zero();
+                for j in 0..N {
+                    let product = -self[[i, j]] * x[j];
+                    let product_error = (-self[[i, j]]).mul_add(x[j], -product);
+                    let new_sum = sum + product;
+                    let virtual_product = new_sum - sum;
+                    let sum_error =
+                        (sum - (new_sum - virtual_product)) + (product - virtual_product);
+                    sum = new_sum;
+                    compensation += sum_error + product_error;
+                }
+                residual[i] = sum + compensation;
+            }
+            x += lu.solve(residual);
+        }
+        Some(x)
+    }
+}
+
+/// `a + b` as the rounded sum and its exact rounding error
+fn two_sum<T: Copy + ClosedOps>(a: T, b: T) -> (T, T) {
+    let sum = a + b;
+    let virtual_b = sum - a;
+    let virtual_a = sum - virtual_b;
+    (sum, (a - virtual_a) + (b - virtual_b))
+}
+
+/// adds `x` to a nonoverlapping expansion (components sorted by increasing magnitude whose
+/// exact sum is the represented value), dropping zero components
+fn grow_expansion<T: Copy + ClosedOps + Zero>(expansion: &[T], x: T) -> Vec<T> {
+    let mut grown = Vec::with_capacity(expansion.len() + 1);
+    let mut carry = x;
+    for &component in expansion {
+        let (sum, error) = two_sum(carry, component);
+        if !error.is_zero() {
+            grown.push(error);
+        }
+        carry = sum;
+    }
+    if !carry.is_zero() {
+        grown.push(carry);
+    }
+    grown
+}
+
+impl<T, const N: usize> SquareMatrix<T, { N }>
+where
+    T: Scalar + ClosedOps + Real + Float + MulAdd<Output = T>,
+{
+    /// exact sign of the determinant of the stored values, e.g. for orientation predicates.
+    /// the rows are scaled by powers of two so the largest entry lies in `[0.5, 1)`, then the
+    /// laplace expansion is evaluated in expansion arithmetic (error-free sums and products),
+    /// so nearly-degenerate inputs get the correct sign instead of a tolerance-based `Zero`
+    /// and the result neither overflows nor underflows for badly scaled rows. the sign is only
+    /// inexact if a product of `N` scaled entries underflows. takes `O(2^N * N)` time and
+    /// `O(2^N)` expansions of memory like `determinant_laplace`
+    pub fn determinant_sign(&self) -> Sign {
+        assert!(
+            N < usize::BITS as usize,
+            "matrix too large for a laplace expansion"
+        );
+        let two = T::one() + T::one();
+        let half = T::one() / two;
+        let mut mat = *self;
+        for m in 0..N {
+            let mut largest = T::zero();
+            for n in 0..N {
+                if mat[[m, n]].abs() > largest {
+                    largest = mat[[m, n]].abs();
+                }
+            }
+            if largest.is_zero() {
+                return Sign::Zero;
+            }
+            let mut scale = T::one();
+            while largest * scale >= T::one() {
+                scale *= half;
+            }
+            while largest * scale < half {
+                scale *= two;
+            }
+            for n in 0..N {
+                mat[[m, n]] *= scale;
+            }
+        }
+
+        let mut minors = vec![Vec::new(); 1 << N];
+        minors[0] = vec![T::one()];
+        for columns in 1usize..1 << N {
+            // expand the minor along its first row
+            let row = N - columns.count_ones() as usize;
+            let mut det = Vec::new();
+            let mut negate = false;
+            for col in 0..N {
+                if columns & (1 << col) == 0 {
+                    continue;
+                }
+                let entry = if negate {
+                    -mat[[row, col]]
+                } else {
+                    mat[[row, col]]
+                };
+                for &component in minors[columns & !(1 << col)].iter() {
+                    let product = entry * component;
+                    let error = entry.mul_add(component, -product);
+                    det = grow_expansion(&det, error);
+                    det = grow_expansion(&det, product);
+                }
+                negate = !negate;
+            }
+            minors[columns] = det;
+        }
+
+        // the largest component of a nonoverlapping expansion determines its sign
+        match minors[(1 << N) - 1].last() {
+            None => Sign::Zero,
+            Some(&largest) if largest < T::zero() => Sign::Negative,
+            Some(_) => Sign::Positive,
+        }
+    }
+}
+
+#[cfg(test)]
+mod lu_tests {
+    use crate::algebra::linear::{Matrix, Pivoting, Sign, SquareMatrix, Vector};
+    use fructose::algebra::lattice::{JoinSemilattice, Lattice, MeetSemilattice};
+    use fructose::operators::{Additive, Multiplicative};
+    use fructose::properties::general::{Identity, Set};
+    use fructose::properties::helpers::sign::Signed;
+    use std::ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+    };
+
+    /// heap allocated and therefore only `Clone`, like arbitrary precision types
+    #[derive(Debug, Clone, Default, PartialEq, PartialOrd)]
+    struct Boxed(Box<f64>);
+
+    macro_rules! impl_boxed_ops {
+        ($($trait:ident $fn:ident $assign:ident $assign_fn:ident $op:tt)*) => {
+            $(
+                impl $trait for Boxed {
+                    type Output = Self;
+                    fn $fn(self, rhs: Self) -> Self {
+                        Boxed(Box::new(*self.0 $op *rhs.0))
+                    }
+                }
+
+                impl $assign for Boxed {
+                    fn $assign_fn(&mut self, rhs: Self) {
+                        *self.0 = *self.0 $op *rhs.0;
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_boxed_ops!(
+        Add add AddAssign add_assign +
+        Sub sub SubAssign sub_assign -
+        Mul mul MulAssign mul_assign *
+        Div div DivAssign div_assign /
+        Rem rem RemAssign rem_assign %
+    );
+
+    impl Neg for Boxed {
+        type Output = Self;
+        fn neg(self) -> Self {
+            Boxed(Box::new(-*self.0))
+        }
+    }
+
+    impl Set<Additive> for Boxed {
+        fn operate(&self, rhs: Self) -> Self {
+            self.clone() + rhs
+        }
+    }
+
+    impl Identity<Additive> for Boxed {
+        fn identity() -> Self {
+            Boxed(Box::new(0.0))
+        }
+
+        fn is_identity(&self) -> bool {
+            *self.0 == 0.0
+        }
+    }
+
+    impl Set<Multiplicative> for Boxed {
+        fn operate(&self, rhs: Self) -> Self {
+            self.clone() * rhs
+        }
+    }
+
+    impl Identity<Multiplicative> for Boxed {
+        fn identity() -> Self {
+            Boxed(Box::new(1.0))
+        }
+
+        fn is_identity(&self) -> bool {
+            *self.0 == 1.0
+        }
+    }
+
+    impl Signed for Boxed {
+        fn abs(&self) -> Self {
+            Boxed(Box::new(self.0.abs()))
+        }
+
+        fn abs_sub(&self, rhs: &Self) -> Self {
+            Boxed(Box::new((*self.0 - *rhs.0).max(0.0)))
+        }
+
+        fn signum(&self) -> Self {
+            Boxed(Box::new(self.0.signum()))
+        }
+
+        fn is_positive(&self) -> bool {
+            *self.0 > 0.0
+        }
+
+        fn is_negative(&self) -> bool {
+            *self.0 < 0.0
+        }
+    }
+
+    impl MeetSemilattice for Boxed {
+        fn infimum(&self, rhs: &Self) -> Self {
+            Boxed(Box::new(self.0.min(*rhs.0)))
+        }
+    }
+
+    impl JoinSemilattice for Boxed {
+        fn supremum(&self, rhs: &Self) -> Self {
+            Boxed(Box::new(self.0.max(*rhs.0)))
+        }
+    }
+
+    impl Lattice for Boxed {}
+
+    fn boxed<const N: usize>(mat: SquareMatrix<f64, N>) -> SquareMatrix<Boxed, N> {
+        Matrix {
+            data: mat.data.map(|col| col.map(|e| Boxed(Box::new(e)))),
+        }
+    }
+
+    #[test]
+    fn solve() {
+        let a = SquareMatrix::new([[2.0, 4.0, -2.0], [1.0, -6.0, 7.0], [1.0, 0.0, 2.0]]);
+        let b = Vector::from([5.0, -2.0, 9.0]);
+        let x = a.solve(b).unwrap();
+        assert_eq!(x, Vector::from([1.0, 1.0, 2.0]));
+    }
+
+    #[test]
+    fn solve_singular() {
+        let a = SquareMatrix::new([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(a.solve(Vector::from([1.0, 1.0])), None);
+    }
+
+    #[test]
+    fn solve_pivoting() {
+        let a = SquareMatrix::<f64, 3>::new([[4.0, 1.0, 0.0], [1.0, 5.0, 2.0], [0.0, 2.0, 6.0]]);
+        let b = Vector::from([1.0, 2.0, 3.0]);
+        let partial = a.solve_with_pivoting(b, Pivoting::Partial).unwrap();
+        let none = a.solve_with_pivoting(b, Pivoting::None).unwrap();
+        assert_eq!(partial, a.solve(b).unwrap());
+        for i in 0..3 {
+            assert!((partial[i] - none[i]).abs() < 1e-12);
+        }
+
+        // regular, but the first pivot is zero
+        let a = SquareMatrix::new([[0.0, 1.0], [1.0, 0.0]]);
+        let b = Vector::from([2.0, 3.0]);
+        assert_eq!(a.solve_with_pivoting(b, Pivoting::None), None);
+        assert_eq!(
+            a.solve_with_pivoting(b, Pivoting::Partial),
+            Some(Vector::from([3.0, 2.0]))
+        );
+    }
+
+    #[test]
+    fn lu_in_place() {
+        let a = SquareMatrix::<f64, 4>::new([
+            [1.0, 4.0, -2.0, 0.5],
+            [3.0, 0.0, 1.0, 2.0],
+            [-1.0, 2.0, 5.0, 1.0],
+            [2.0, 1.0, 0.0, -3.0],
+        ]);
+        let mut packed = a;
+        let perm = packed.lu_in_place();
+        assert_eq!(perm, a.lu().unwrap().pivots);
+
+        let mut l = SquareMatrix::<f64, 4>::default();
+        let mut u = SquareMatrix::<f64, 4>::default();
+        for m in 0..4 {
+            for n in 0..4 {
+                match m.cmp(&n) {
+                    std::cmp::Ordering::Greater => l[[m, n]] = packed[[m, n]],
+                    std::cmp::Ordering::Equal => {
+                        l[[m, n]] = 1.0;
+                        u[[m, n]] = packed[[m, n]];
+                    }
+                    std::cmp::Ordering::Less => u[[m, n]] = packed[[m, n]],
+                }
+            }
+        }
+        let product = l * u;
+        for m in 0..4 {
+            for n in 0..4 {
+                assert!((product[[m, n]] - a[[perm[m], n]]).abs() < 1e-12);
+            }
+        }
+
+        // singular, the second column has no pivot left
+        let mut singular =
+            SquareMatrix::<f64, 3>::new([[1.0, 2.0, 0.0], [2.0, 4.0, 0.0], [0.0, 1.0, 1.0]]);
+        singular.lu_in_place();
+        assert_eq!(singular[[1, 1]], 0.0);
+    }
+
+    #[test]
+    fn inverse_lu() {
+        let a = SquareMatrix::<f64, 3>::new([[2.0, 1.0, 0.0], [-1.0, 0.0, 3.0], [4.0, 1.0, 1.0]]);
+        let inverse = a.inverse_lu().unwrap();
+        // gauss-jordan on [A | I]
+        let mut expected =
+            SquareMatrix::new([[-3.0, -1.0, 3.0], [13.0, 2.0, -6.0], [-1.0, 2.0, 1.0]]);
+        expected /= 7.0;
+        let identity = a * inverse;
+        for m in 0..3 {
+            for n in 0..3 {
+                assert!((inverse[[m, n]] - expected[[m, n]]).abs() < 1e-12);
+                let one = if m == n { 1.0 } else { 0.0 };
+                assert!((identity[[m, n]] - one).abs() < 1e-12);
+            }
+        }
+
+        let singular = SquareMatrix::<f64, 2>::new([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(singular.inverse_lu(), None);
+    }
+
+    #[test]
+    fn determinant() {
+        let a = SquareMatrix::<f64, 3>::new([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 2.0]]);
+        let det = a.lu().unwrap().determinant();
+        assert!((det - a.determinant_laplace()).abs() < 1e-12);
+        assert!((a.determinant_equilibrated() - det).abs() < 1e-12);
+    }
+
+    #[test]
+    fn determinant_pivot_sign() {
+        // regression test, the first two rows are swapped so the determinant is the negated
+        // product of the diagonal of U
+        let a = SquareMatrix::<f64, 3>::from(((0.0, 2.0, 1.0), (3.0, 1.0, 0.0), (0.0, 0.0, 4.0)));
+        let lu = a.lu().unwrap();
+        assert_eq!(lu.pivots, [1, 0, 2]);
+        assert_eq!(lu.swaps, 1);
+        let diagonal = lu.packed[[0, 0]] * lu.packed[[1, 1]] * lu.packed[[2, 2]];
+        assert_eq!(diagonal, 24.0);
+        assert_eq!(lu.determinant(), -24.0);
+        assert_eq!(a.determinant_laplace(), -24.0);
+
+        let a = SquareMatrix::<f64, 4>::from((
+            (0.0, 1.0, 0.0, 0.0),
+            (2.0, 0.0, 0.0, 0.0),
+            (0.0, 0.0, 3.0, 0.0),
+            (0.0, 0.0, 0.0, 4.0),
+        ));
+        assert_eq!(a.lu().unwrap().swaps, 1);
+        assert_eq!(a.determinant(), -24.0);
+    }
+
+    #[test]
+    fn determinant_equilibrated() {
+        // the first column dominates, so partial pivoting alone picks poor pivots
+        let a = SquareMatrix::<f32, 3>::new([
+            [-4000.0, 64.0, -88.0],
+            [-99000.0, -13.0, 2.0],
+            [67000.0, 51.0, -54.0],
+        ]);
+        let exact = a.map_into(|e| e as f64).determinant_bareiss();
+        let error = |det: f32| ((det as f64 - exact) / exact).abs();
+
+        let plain = error(a.lu().unwrap().determinant());
+        let equilibrated = error(a.determinant_equilibrated());
+        assert!(equilibrated * 100.0 < plain);
+    }
+
+    #[test]
+    fn solve_refined() {
+        // 4x4 hilbert matrix, condition number ~1.5e4
+        let mut a = SquareMatrix::<f32, 4>::default();
+        let mut a_exact = SquareMatrix::<f64, 4>::default();
+        for m in 0..4 {
+            for n in 0..4 {
+                a[[m, n]] = 1.0 / (m + n + 1) as f32;
+                a_exact[[m, n]] = a[[m, n]] as f64;
+            }
+        }
+        let b = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let exact = a_exact.solve(Vector::from([1.0, 2.0, 3.0, 4.0])).unwrap();
+
+        let error = |x: Vector<f32, 4>| {
+            let mut sum = 0.0;
+            for i in 0..4 {
+                sum += (x[i] as f64 - exact[i]).powi(2);
+            }
+            sum.sqrt()
+        };
+
+        let plain = a.solve(b).unwrap();
+        let refined = a.solve_refined(b, 3).unwrap();
+        assert!(error(refined) < error(plain) / 10.0);
+    }
+
+    #[test]
+    fn determinant_sign() {
+        // orientation of the points a, b, c (rows [x, y, 1]), c lies 4e-13 left of ab
+        let orientation = |a: [f64; 2], b: [f64; 2], c: [f64; 2]| {
+            SquareMatrix::new([[a[0], b[0], c[0]], [a[1], b[1], c[1]], [1.0, 1.0, 1.0]])
+                .determinant_sign()
+        };
+        let (a, b, c) = ([0.1, 0.1], [0.5, 0.5], [0.9, 0.9 + 1e-12]);
+        assert_eq!(orientation(a, b, c), Sign::Positive);
+        assert_eq!(orientation(a, c, b), Sign::Negative);
+        assert_eq!(orientation([0.0, 0.0], [1.0, 1.0], [2.0, 2.0]), Sign::Zero);
+
+        // c lies 2e-15 right of ab, far below any fixed EPSILON based cutoff
+        let (a, b, c) = ([1.0, 1.0], [2.0, 2.0 + 1e-15], [3.0, 3.0]);
+        let stored =
+            SquareMatrix::<f64, 3>::new([[a[0], b[0], c[0]], [a[1], b[1], c[1]], [1.0, 1.0, 1.0]]);
+        assert!(stored.determinant() < 0.0);
+        assert_eq!(orientation(a, b, c), Sign::Negative);
+        assert_eq!(orientation(b, a, c), Sign::Positive);
+
+        // the determinant itself overflows or underflows
+        let large =
+            SquareMatrix::<f64, 3>::new([[0.0, 1e200, 0.0], [1e200, 0.0, 0.0], [0.0, 0.0, 1e200]]);
+        assert!(large.determinant().is_infinite());
+        assert_eq!(large.determinant_sign(), Sign::Negative);
+        let small = SquareMatrix::<f64, 2>::new([[1e-200, 0.0], [0.0, 1e-200]]);
+        assert_eq!(small.determinant_bareiss(), 0.0);
+        assert_eq!(small.determinant_sign(), Sign::Positive);
+        let mixed = SquareMatrix::<f64, 2>::new([[1e200, 0.0], [0.0, -1e-200]]);
+        assert_eq!(mixed.determinant_sign(), Sign::Negative);
+    }
+
+    #[test]
+    fn clone_scalar() {
+        let mat =
+            SquareMatrix::<f64, 3>::new([[2.0, 4.0, -2.0], [1.0, -6.0, 7.0], [1.0, 0.0, 2.0]]);
+        let det = mat.lu().unwrap().determinant();
+        assert!((det - mat.determinant_bareiss()).abs() < 1e-12);
+
+        // `determinant` and `inverse` need `Copy`, clone-only scalars go through
+        // `determinant_bareiss` and `inverse_lu`
+        let cloned = boxed(mat);
+        assert!((*cloned.determinant_bareiss().0 - det).abs() < 1e-12);
+        assert!((*cloned.lu().unwrap().determinant().0 - det).abs() < 1e-12);
+
+        let inverse = mat.inverse_lu().unwrap();
+        let cloned_inverse = cloned.inverse_lu().unwrap();
+        for m in 0..3 {
+            for n in 0..3 {
+                assert!((*cloned_inverse[[m, n]].0 - inverse[[m, n]]).abs() < 1e-12);
+            }
+        }
+        let singular = boxed(SquareMatrix::new([[1.0, 2.0], [2.0, 4.0]]));
+        assert!(singular.lu().is_none());
+        assert!(singular.inverse_lu().is_none());
+        assert_eq!(*singular.determinant_bareiss().0, 0.0);
+    }
+}
@@ -1,8 +1,9 @@
-use crate::algebra::linear::{Scalar, Vector};
+use crate::algebra::linear::{DVector, Scalar, Vector};
 use fructose::algebra::lattice::Lattice;
 use fructose::operators::{
     ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedOps, ClosedRem, ClosedSub,
 };
+use fructose::properties::helpers::float::Float;
 use fructose::properties::helpers::identity::{One, Zero};
 use fructose::properties::helpers::sign::Signed;
 use std::alloc::Layout;
@@ -21,11 +22,11 @@ pub struct Matrix<T, const M: usize, const N: usize> {
     pub data: [[T; M]; N],
 }
 
-impl<T: Default + Copy, const M: usize, const N: usize> Default for Matrix<T, { M }, { N }> {
+impl<T: Default, const M: usize, const N: usize> Default for Matrix<T, { M }, { N }> {
     #[inline]
     fn default() -> Self {
         Self {
-            data: [[T::default(); M]; N],
+            data: [(); N].map(|_| [(); M].map(|_| T::default())),
         }
     }
 }
@@ -117,6 +118,31 @@ impl<T, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
 }
 
 impl<T: Default + Copy, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+    /// stacks the columns of the matrix on top of each other
+    #[inline]
+    pub fn vectorize(&self) -> DVector<T> {
+        DVector::new(self.as_slice().to_vec())
+    }
+
+    /// elements row by row: `[a_00, a_01, .., a_0N, a_10, ..]`, the layout C arrays and
+    /// most file formats use
+    pub fn to_row_major(&self) -> Vec<T> {
+        let mut elements = Vec::with_capacity(M * N);
+        for m in 0..M {
+            for n in 0..N {
+                elements.push(self.data[n][m]);
+            }
+        }
+        elements
+    }
+
+    /// elements column by column: `[a_00, a_10, .., a_M0, a_01, ..]`, the layout the
+    /// matrix is stored in (and the one OpenGL and fortran expect)
+    #[inline]
+    pub fn to_col_major(&self) -> Vec<T> {
+        self.as_slice().to_vec()
+    }
+
     #[inline]
     pub fn to_vectors(&self) -> [Vector<T, { M }>; { N }] {
         let mut vectors = [Vector::default(); N];
@@ -146,12 +172,118 @@ impl<T: Scalar, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
         vector
     }
 
+    /// copies the overlapping top-left block into a `P x Q` matrix, the remaining
+    /// elements are set to `fill`
+    #[inline]
+    pub fn resize<const P: usize, const Q: usize>(&self, fill: T) -> Matrix<T, { P }, { Q }> {
+        let mut mat = Matrix::broadcast(fill);
+        for n in 0..N.min(Q) {
+            for m in 0..M.min(P) {
+                mat.data[n][m] = self.data[n][m];
+            }
+        }
+        mat
+    }
+
+    /// the transposed `N x M` matrix, element `[[m, n]]` ends up at `[[n, m]]`
+    #[inline]
+    pub fn transpose(&self) -> Matrix<T, { N }, { M }> {
+        let mut transposed = Matrix::default();
+        for n in 0..N {
+            for m in 0..M {
+                transposed.data[m][n] = self.data[n][m];
+            }
+        }
+        transposed
+    }
+
+    /// like `map` but the closure may return another element type
+    #[inline]
+    pub fn map_into<U: Default + Copy, F: Fn(T) -> U>(&self, f: F) -> Matrix<U, { M }, { N }> {
+        let mut mat = Matrix::default();
+        for n in 0..N {
+            for m in 0..M {
+                mat.data[n][m] = f(self.data[n][m]);
+            }
+        }
+        mat
+    }
+
     #[inline]
     pub fn apply<F: Fn(T) -> T>(&mut self, f: F) {
         self.data
             .iter_mut()
             .for_each(|e| e.iter_mut().for_each(|e| *e = f(*e)));
     }
+
+    /// row `i` of the result is row `perm[i]` of `self`, e.g. with the pivots of an LU
+    /// decomposition. panics if `perm` isn't a permutation of `0..M`
+    pub fn permute_rows(&self, perm: &[usize]) -> Self {
+        assert_permutation(perm, M);
+        let mut mat = *self;
+        for n in 0..N {
+            for (m, &from) in perm.iter().enumerate() {
+                mat.data[n][m] = self.data[n][from];
+            }
+        }
+        mat
+    }
+
+    /// column `j` of the result is column `perm[j]` of `self`,
+    /// panics if `perm` isn't a permutation of `0..N`
+    pub fn permute_columns(&self, perm: &[usize]) -> Self {
+        assert_permutation(perm, N);
+        let mut mat = *self;
+        for (n, &from) in perm.iter().enumerate() {
+            mat.data[n] = self.data[from];
+        }
+        mat
+    }
+
+    /// applies `f` to every column, e.g. for aggregations like the median of each column
+    pub fn reduce_cols<A, F: Fn(&[T]) -> A>(&self, f: F) -> Vec<A> {
+        self.data.iter().map(|col| f(col)).collect()
+    }
+
+    /// applies `f` to every row, the row is copied into a contiguous buffer first
+    pub fn reduce_rows<A, F: Fn(&[T]) -> A>(&self, f: F) -> Vec<A> {
+        let mut row = [T::default(); N];
+        (0..M)
+            .map(|m| {
+                for (n, e) in row.iter_mut().enumerate() {
+                    *e = self.data[n][m];
+                }
+                f(&row)
+            })
+            .collect()
+    }
+}
+
+fn assert_permutation(perm: &[usize], len: usize) {
+    assert_eq!(perm.len(), len, "permutation has the wrong length");
+    let mut seen = vec![false; len];
+    for &i in perm {
+        assert!(
+            i < len && !seen[i],
+            "{:?} is not a permutation of 0..{}",
+            perm,
+            len
+        );
+        seen[i] = true;
+    }
+}
+
+impl<T, const M: usize> SquareMatrix<T, { M }> {
+    /// transposes the matrix in place by swapping the elements across the diagonal
+    #[inline]
+    pub fn transpose_square(&mut self) {
+        for n in 1..M {
+            let (left, right) = self.data.split_at_mut(n);
+            for (m, col) in left.iter_mut().enumerate() {
+                std::mem::swap(&mut col[n], &mut right[0][m]);
+            }
+        }
+    }
 }
 
 impl<T: Scalar + Signed, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
@@ -169,6 +301,13 @@ impl<T: Scalar + Signed, const M: usize, const N: usize> Matrix<T, { M }, { N }>
         vec.abs();
         vec
     }
+
+    /// element wise sign, `-1`, `0` or `1`. like `f64::signum` a float `0.0` maps to `1`
+    /// and `-0.0` to `-1`
+    #[inline]
+    pub fn signum(&self) -> Self {
+        self.map(|e| e.signum())
+    }
 }
 
 impl<T: Scalar + Lattice, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
@@ -217,22 +356,184 @@ impl<T: Scalar + Lattice, const M: usize, const N: usize> Matrix<T, { M }, { N }
     }
 }
 
+impl<T: Scalar + Float, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+    /// `true` if no element is infinite or NaN
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.data
+            .iter()
+            .flatten()
+            .all(|e| !e.is_nan() && !e.is_infinite())
+    }
+
+    #[inline]
+    pub fn has_nan(&self) -> bool {
+        self.data.iter().flatten().any(|e| e.is_nan())
+    }
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+    /// computes `Aᵀ A`, only the upper triangle is calculated and mirrored into the lower one
+    pub fn gram(&self) -> SquareMatrix<T, { N }> {
+        let mut gram = SquareMatrix::default();
+        for i in 0..N {
+            for j in i..N {
+                let mut sum = T::default();
+                for m in 0..M {
+                    sum += self[[m, i]] * self[[m, j]];
+                }
+                gram[[i, j]] = sum;
+                gram[[j, i]] = sum;
+            }
+        }
+        gram
+    }
+
+    /// `Aᵀ A`, the inner products of the columns. same as `gram`
+    #[inline]
+    pub fn gram_lower(&self) -> SquareMatrix<T, { N }> {
+        self.gram()
+    }
+
+    /// `A Aᵀ`, the inner products of the rows. only the upper triangle is calculated and
+    /// mirrored into the lower one
+    pub fn gram_upper(&self) -> SquareMatrix<T, { M }> {
+        let mut gram = SquareMatrix::default();
+        for i in 0..M {
+            for j in i..M {
+                let mut sum = T::default();
+                for n in 0..N {
+                    sum += self.data[n][i] * self.data[n][j];
+                }
+                gram[[i, j]] = sum;
+                gram[[j, i]] = sum;
+            }
+        }
+        gram
+    }
+
+    /// `selfᵀ * other` without building the transpose, element `[[i, j]]` is the inner
+    /// product of column `i` of `self` and column `j` of `other`
+    pub fn transpose_mul<const P: usize>(
+        &self,
+        other: &Matrix<T, { M }, { P }>,
+    ) -> Matrix<T, { N }, { P }> {
+        let mut mat = Matrix::default();
+        for p in 0..P {
+            for n in 0..N {
+                let mut sum = T::default();
+                for m in 0..M {
+                    sum += self.data[n][m] * other.data[p][m];
+                }
+                mat.data[p][n] = sum;
+            }
+        }
+        mat
+    }
+
+    /// frobenius inner product `Σ self[i, j] * other[i, j]`, equal to `trace(selfᵀ * other)`
+    pub fn frobenius_inner(&self, other: &Self) -> T {
+        let mut sum = T::default();
+        for n in 0..N {
+            for m in 0..M {
+                sum += self.data[n][m] * other.data[n][m];
+            }
+        }
+        sum
+    }
+
+    /// `self * diag(diag)` without building the diagonal matrix, scales column `n` by `diag[n]`
+    #[inline]
+    pub fn mul_diagonal(&self, diag: &Vector<T, { N }>) -> Self {
+        let mut mat = *self;
+        for n in 0..N {
+            for m in 0..M {
+                mat.data[n][m] *= diag[n];
+            }
+        }
+        mat
+    }
+}
+
+impl<T: Scalar + Zero + One, const M: usize> SquareMatrix<T, { M }> {
+    /// ones on the diagonal and zeros everywhere else
+    #[inline]
+    pub fn identity() -> Self {
+        let mut mat = Self::broadcast(T::zero());
+        for m in 0..M {
+            mat[[m, m]] = T::one();
+        }
+        mat
+    }
+}
+
+impl<T: Scalar + ClosedAdd, const M: usize> SquareMatrix<T, { M }> {
+    /// sum of the diagonal, `0` (the empty sum) for a `0x0` matrix
+    #[inline]
+    pub fn trace(&self) -> T {
+        let mut trace = T::default();
+        for m in 0..M {
+            trace += self[[m, m]];
+        }
+        trace
+    }
+}
+
 impl<T: Scalar + Zero + One + ClosedOps, const M: usize> SquareMatrix<T, { M }> {
     #[inline]
     pub fn mul_identity() -> Self {
+        Self::identity()
+    }
+
+    /// `trace(selfᵏ)`, the sum of the k-th powers of the eigenvalues. the power is computed by
+    /// repeated squaring. for an adjacency matrix this counts the closed walks of length `k`
+    pub fn trace_of_power(&self, k: u32) -> T {
+        let mut power = Self::identity();
+        let mut base = *self;
+        let mut k = k;
+        while k > 0 {
+            if k & 1 == 1 {
+                power = power * base;
+            }
+            k >>= 1;
+            if k > 0 {
+                base = base * base;
+            }
+        }
+        power.trace()
+    }
+
+    /// spherical part `(trace / M) * I`, the mean of the diagonal on the diagonal
+    pub fn isotropic(&self) -> Self {
         let mut mat = Self::zero();
+        if M == 0 {
+            return mat;
+        }
+        let mut n = T::zero();
+        for _ in 0..M {
+            n += T::one();
+        }
+        let mean = self.trace() / n;
         for m in 0..M {
-            mat[[m, m]] = T::one();
+            mat[[m, m]] = mean;
         }
         mat
     }
 
+    /// trace-free part `self - (trace / M) * I`, e.g. the deviatoric stress of a stress tensor
     #[inline]
-    pub fn determinant(&self) -> T {
+    pub fn deviatoric(&self) -> Self {
+        *self - self.isotropic()
+    }
+
+    /// closed form determinant up to `3x3`, `None` for larger matrices. the determinant of a
+    /// `0x0` matrix is `1` (the empty product), consistent with the laplace expansion and
+    /// `det(A ⊕ B) = det(A) det(B)`
+    fn determinant_closed_form(&self) -> Option<T> {
         match M {
-            0 => T::one(),
-            1 => self[[0, 0]],
-            2 => self[[0, 0]] * self[[1, 1]] - self[[1, 0]] * self[[1, 0]],
+            0 => Some(T::one()),
+            1 => Some(self[[0, 0]]),
+            2 => Some(self[[0, 0]] * self[[1, 1]] - self[[0, 1]] * self[[1, 0]]),
             3 => {
                 let e11 = self[[0, 0]];
                 let e12 = self[[0, 1]];
@@ -250,12 +551,237 @@ impl<T: Scalar + Zero + One + ClosedOps, const M: usize> SquareMatrix<T, { M }>
                 let minor_2 = e21 * e33 - e31 * e23;
                 let minor_3 = e21 * e32 - e31 * e22;
 
-                e11 * minor_1 - e12 * minor_2 + e13 * minor_3
+                Some(e11 * minor_1 - e12 * minor_2 + e13 * minor_3)
+            }
+            _ => None,
+        }
+    }
+
+    /// laplace (cofactor) expansion with memoized minors, exact for integer matrices.
+    /// the minor built from the last `k` rows is identified by its set of columns,
+    /// so this takes `O(2^M * M)` time and `O(2^M)` memory
+    pub fn determinant_laplace(&self) -> T {
+        assert!(
+            M < usize::BITS as usize,
+            "matrix too large for a laplace expansion"
+        );
+        let mut minors = vec![T::zero(); 1 << M];
+        minors[0] = T::one();
+        for columns in 1usize..1 << M {
+            // expand the minor along its first row
+            let row = M - columns.count_ones() as usize;
+            let mut det = T::zero();
+            let mut negate = false;
+            for col in 0..M {
+                if columns & (1 << col) == 0 {
+                    continue;
+                }
+                let term = self[[row, col]] * minors[columns & !(1 << col)];
+                if negate {
+                    det -= term;
+                } else {
+                    det += term;
+                }
+                negate = !negate;
+            }
+            minors[columns] = det;
+        }
+        minors[(1 << M) - 1]
+    }
+
+    /// coefficients of `det(λI - self)` from the highest power down, `[1, c_(M-1), .., c_0]`,
+    /// computed with the Faddeev–LeVerrier algorithm. only uses matrix products, traces and
+    /// exact divisions by `1..=M`, so integer matrices yield exact coefficients
+    pub fn characteristic_polynomial(&self) -> Vec<T> {
+        let mut coefficients = vec![T::one()];
+        let mut aux = Self::zero();
+        let mut k = T::zero();
+        for _ in 0..M {
+            k += T::one();
+            let c = *coefficients.last().unwrap();
+            aux = *self * aux;
+            for m in 0..M {
+                aux[[m, m]] += c;
+            }
+
+            coefficients.push(T::zero() - (*self * aux).trace() / k);
+        }
+        coefficients
+    }
+
+    /// approximates the inverse with the first `terms` terms of the neumann series
+    /// `I + N + N² + ..` where `N = I - self`. only converges if the spectral radius of `N`
+    /// is below `1`, i.e. `self` is close to the identity
+    pub fn inverse_neumann(&self, terms: usize) -> Self {
+        let identity = Self::mul_identity();
+        let n = identity - *self;
+        let mut inverse = Self::zero();
+        for _ in 0..terms {
+            inverse = identity + n * inverse;
+        }
+        inverse
+    }
+}
+
+/// the algorithm `SquareMatrix::determinant` uses above `3x3`. exact scalars (integers,
+/// `Mod<P>`) use the fraction-free bareiss algorithm, floating point scalars the LU
+/// decomposition with partial pivoting, which picks the largest pivots and doesn't overflow
+/// as easily. custom scalars can forward to `determinant_bareiss`, `determinant_lu` or
+/// `determinant_laplace`
+pub trait DeterminantAlgorithm: Sized {
+    fn determinant<const M: usize>(mat: &SquareMatrix<Self, { M }>) -> Self;
+}
+
+macro_rules! impl_determinant_algorithm {
+    ($method:ident: $($t:ty)*) => {
+        $(
+            impl DeterminantAlgorithm for $t {
+                #[inline]
+                fn determinant<const M: usize>(mat: &SquareMatrix<Self, { M }>) -> Self {
+                    mat.$method()
+                }
+            }
+        )*
+    };
+}
+
+impl_determinant_algorithm!(determinant_bareiss: i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+impl_determinant_algorithm!(determinant_lu: f32 f64);
+
+impl<T: Scalar + Zero + One + ClosedOps + DeterminantAlgorithm, const M: usize>
+    SquareMatrix<T, { M }>
+{
+    /// closed form up to `3x3`, larger matrices use the algorithm of the scalar
+    /// (see [`DeterminantAlgorithm`]). requires `Copy` scalars, use `determinant_bareiss`
+    /// for scalars that are only `Clone`
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self.determinant_closed_form()
+            .unwrap_or_else(|| T::determinant(self))
+    }
+}
+
+impl<T: Scalar + Zero + One + ClosedOps + Signed + Lattice, const M: usize> SquareMatrix<T, { M }> {
+    /// inverse via the adjugate divided by the determinant up to `3x3`, larger matrices use
+    /// gauss-jordan elimination with partial pivoting. `None` if `self` is singular.
+    /// requires `Copy` scalars, use `inverse_lu` for scalars that are only `Clone`
+    pub fn inverse(&self) -> Option<Self> {
+        if M > 3 {
+            return self.inverse_gauss_jordan();
+        }
+        let det = self.determinant_closed_form()?;
+        if det.is_zero() {
+            return None;
+        }
+        let mut inverse = *self;
+        match M {
+            0 => {}
+            1 => inverse[[0, 0]] = T::one() / det,
+            2 => {
+                inverse[[0, 0]] = self[[1, 1]] / det;
+                inverse[[0, 1]] = -self[[0, 1]] / det;
+                inverse[[1, 0]] = -self[[1, 0]] / det;
+                inverse[[1, 1]] = self[[0, 0]] / det;
             }
             _ => {
-                unimplemented!("TODO: Add LU Decomposition")
+                // element (m, n) of the inverse is the cofactor (n, m) divided by the determinant
+                for m in 0..3 {
+                    for n in 0..3 {
+                        let (r1, r2) = ((n + 1) % 3, (n + 2) % 3);
+                        let (c1, c2) = ((m + 1) % 3, (m + 2) % 3);
+                        inverse[[m, n]] = (self[[r1, c1]] * self[[r2, c2]]
+                            - self[[r1, c2]] * self[[r2, c1]])
+                            / det;
+                    }
+                }
             }
         }
+        Some(inverse)
+    }
+
+    fn inverse_gauss_jordan(&self) -> Option<Self> {
+        let mut mat = *self;
+        let mut inverse = Self::identity();
+        for k in 0..M {
+            let mut pivot = k;
+            for i in k + 1..M {
+                if mat[[i, k]].abs() > mat[[pivot, k]].abs() {
+                    pivot = i;
+                }
+            }
+            if mat[[pivot, k]].is_zero() {
+                return None;
+            }
+            if pivot != k {
+                for n in 0..M {
+                    mat.data[n].swap(pivot, k);
+                    inverse.data[n].swap(pivot, k);
+                }
+            }
+
+            let scale = mat[[k, k]];
+            for n in 0..M {
+                mat[[k, n]] /= scale;
+                inverse[[k, n]] /= scale;
+            }
+            for i in 0..M {
+                if i == k || mat[[i, k]].is_zero() {
+                    continue;
+                }
+                let factor = mat[[i, k]];
+                for n in 0..M {
+                    let (sub, sub_inverse) = (factor * mat[[k, n]], factor * inverse[[k, n]]);
+                    mat[[i, n]] -= sub;
+                    inverse[[i, n]] -= sub_inverse;
+                }
+            }
+        }
+        Some(inverse)
+    }
+}
+
+impl<T: Clone + Zero + One + ClosedOps, const M: usize> SquareMatrix<T, { M }> {
+    /// fraction-free determinant (Bareiss algorithm), every division is exact
+    /// so integer matrices yield the exact integer determinant. only clones the
+    /// elements, so it also works for scalars that aren't `Copy`
+    pub fn determinant_bareiss(&self) -> T {
+        if M == 0 {
+            return T::one();
+        }
+
+        let mut mat = self.clone();
+        let mut negate = false;
+        let mut previous = T::one();
+
+        for k in 0..M - 1 {
+            if mat[[k, k]].is_zero() {
+                match (k + 1..M).find(|&i| !mat[[i, k]].is_zero()) {
+                    Some(i) => {
+                        for n in 0..M {
+                            mat.data[n].swap(i, k);
+                        }
+                        negate = !negate;
+                    }
+                    None => return T::zero(),
+                }
+            }
+
+            for i in k + 1..M {
+                for j in k + 1..M {
+                    mat[[i, j]] = (mat[[i, j]].clone() * mat[[k, k]].clone()
+                        - mat[[i, k]].clone() * mat[[k, j]].clone())
+                        / previous.clone();
+                }
+            }
+            previous = mat[[k, k]].clone();
+        }
+
+        let det = mat[[M - 1, M - 1]].clone();
+        if negate {
+            T::zero() - det
+        } else {
+            det
+        }
     }
 }
 
@@ -323,6 +849,27 @@ impl<T: Scalar + ClosedSub, const M: usize, const N: usize> SubAssign for Matrix
     }
 }
 
+macro_rules! impl_checked_sub {
+    ($($set:ty)*) => {
+        $(
+            impl<const M: usize, const N: usize> Matrix<$set, { M }, { N }> {
+                /// element wise subtraction, `None` if any element would underflow
+                pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    let mut mat = Matrix::default();
+                    for m in 0..M {
+                        for n in 0..N {
+                            mat[[m, n]] = self[[m, n]].checked_sub(other[[m, n]])?;
+                        }
+                    }
+                    Some(mat)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_sub!(u8 u16 u32 u64 u128 usize);
+
 impl<T: Scalar + ClosedMul + ClosedAdd, const M: usize, const N: usize, const P: usize>
     Mul<Matrix<T, { N }, { P }>> for Matrix<T, { M }, { N }>
 {
@@ -351,7 +898,7 @@ impl<T: Scalar + ClosedMul, const M: usize, const N: usize> Mul<T> for Matrix<T,
                 mat[[m, n]] *= rhs
             }
         }
-        self
+        mat
     }
 }
 
@@ -377,7 +924,7 @@ impl<T: Scalar + ClosedDiv, const M: usize, const N: usize> Div<T> for Matrix<T,
                 mat[[m, n]] /= rhs
             }
         }
-        self
+        mat
     }
 }
 
@@ -466,9 +1013,35 @@ impl<T: FromStr + Default + Copy, const M: usize, const N: usize> From<String>
     }
 }
 
+/// tuples of rows, `((a, b), (c, d))` is the matrix with the rows `a b` and `c d`
+impl<T> From<((T, T), (T, T))> for SquareMatrix<T, 2> {
+    fn from(((a, b), (c, d)): ((T, T), (T, T))) -> Self {
+        Matrix::new([[a, c], [b, d]])
+    }
+}
+
+impl<T> From<((T, T, T), (T, T, T), (T, T, T))> for SquareMatrix<T, 3> {
+    fn from(((a, b, c), (d, e, f), (g, h, i)): ((T, T, T), (T, T, T), (T, T, T))) -> Self {
+        Matrix::new([[a, d, g], [b, e, h], [c, f, i]])
+    }
+}
+
+impl<T> From<((T, T, T, T), (T, T, T, T), (T, T, T, T), (T, T, T, T))> for SquareMatrix<T, 4> {
+    fn from(
+        ((a, b, c, d), (e, f, g, h), (i, j, k, l), (m, n, o, p)): (
+            (T, T, T, T),
+            (T, T, T, T),
+            (T, T, T, T),
+            (T, T, T, T),
+        ),
+    ) -> Self {
+        Matrix::new([[a, e, i, m], [b, f, j, n], [c, g, k, o], [d, h, l, p]])
+    }
+}
+
 #[cfg(test)]
 mod mat_tests {
-    use crate::algebra::linear::{Matrix, Vector};
+    use crate::algebra::linear::{Matrix, SquareMatrix, Vector};
 
     #[test]
     fn parse() {
@@ -479,4 +1052,507 @@ mod mat_tests {
         assert_eq!(vec, Vector::new([[2, 3, -5]]));
         assert_eq!(mat, Matrix::new([[2, 3], [-1, 4], [0, -2]]));
     }
+
+    #[test]
+    fn from_tuples() {
+        let mat = SquareMatrix::from(((1, 2), (3, 4)));
+        assert_eq!(mat[[0, 1]], 2);
+        assert_eq!(mat, SquareMatrix::new([[1, 3], [2, 4]]));
+        let mat = SquareMatrix::from(((1, 2, 3), (4, 5, 6), (7, 8, 9)));
+        assert_eq!(mat, Matrix::from(String::from("1 4 7;2 5 8;3 6 9")));
+        let mat = SquareMatrix::from((
+            (1, 2, 3, 4),
+            (5, 6, 7, 8),
+            (9, 10, 11, 12),
+            (13, 14, 15, 16),
+        ));
+        assert_eq!(mat[[2, 3]], 12);
+        assert_eq!(mat[[3, 0]], 13);
+    }
+
+    #[test]
+    fn scalar_mul_div() {
+        let mat = SquareMatrix::<f64, 2>::from(((1.0, 2.0), (3.0, 4.0)));
+        assert_eq!((mat * 2.0)[[0, 0]], 2.0);
+        assert_eq!((mat / 2.0)[[0, 0]], 0.5);
+        assert_eq!(mat * 2.0, SquareMatrix::from(((2.0, 4.0), (6.0, 8.0))));
+        assert_eq!(mat / 2.0, SquareMatrix::from(((0.5, 1.0), (1.5, 2.0))));
+    }
+
+    #[test]
+    fn deviatoric() {
+        let stress =
+            SquareMatrix::<f64, 3>::new([[4.0, 1.0, -2.0], [1.0, -3.0, 0.5], [-2.0, 0.5, 8.0]]);
+        let isotropic = stress.isotropic();
+        let deviatoric = stress.deviatoric();
+        assert_eq!(deviatoric + isotropic, stress);
+        assert_eq!(deviatoric.trace(), 0.0);
+        assert_eq!(isotropic, SquareMatrix::mul_identity() * 3.0);
+        assert_eq!(deviatoric[[0, 1]], 1.0);
+    }
+
+    #[test]
+    fn frobenius_inner() {
+        let a = Matrix::<i32, 2, 3>::new([[1, -2], [3, 0], [4, 5]]);
+        let b = Matrix::<i32, 2, 3>::new([[2, 1], [-1, 6], [0, 3]]);
+        assert_eq!(a.frobenius_inner(&b), 12);
+        assert_eq!(a.frobenius_inner(&b), (a.transpose() * b).trace());
+        assert_eq!(b.frobenius_inner(&a), a.frobenius_inner(&b));
+        // the squared frobenius norm
+        assert_eq!(a.frobenius_inner(&a), 55);
+        assert_eq!(a.frobenius_inner(&a), a.gram().trace());
+    }
+
+    #[test]
+    fn identity() {
+        let mat = SquareMatrix::<i32, 2>::from(((1, 2), (3, 4)));
+        assert_eq!(SquareMatrix::identity() * mat, mat);
+        assert_eq!(mat * SquareMatrix::identity(), mat);
+        let mat =
+            SquareMatrix::<f64, 3>::new([[2.0, -1.0, 0.5], [0.0, 3.0, 1.0], [4.0, 1.0, -2.0]]);
+        assert_eq!(SquareMatrix::identity() * mat, mat);
+        let mat = SquareMatrix::<i64, 5>::broadcast(7);
+        assert_eq!(SquareMatrix::identity() * mat, mat);
+
+        assert_eq!(SquareMatrix::<i32, 4>::identity().trace(), 4);
+        assert_eq!(SquareMatrix::<f64, 3>::identity().trace(), 3.0);
+        assert_eq!(SquareMatrix::<i32, 0>::identity().trace(), 0);
+        assert_eq!(
+            SquareMatrix::<i32, 2>::identity(),
+            SquareMatrix::from(((1, 0), (0, 1)))
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        fn assert_identity<const M: usize>(mat: SquareMatrix<f64, M>) {
+            let product = mat * mat.inverse().unwrap();
+            for m in 0..M {
+                for n in 0..M {
+                    let expected = if m == n { 1.0 } else { 0.0 };
+                    assert!((product[[m, n]] - expected).abs() < 1e-12, "{:?}", product);
+                }
+            }
+        }
+
+        assert_identity(SquareMatrix::<f64, 1>::new([[4.0]]));
+        assert_identity(SquareMatrix::from(((1.0, 2.0), (3.0, 4.0))));
+        assert_identity(SquareMatrix::from((
+            (2.0, -1.0, 0.5),
+            (0.0, 3.0, 1.0),
+            (4.0, 1.0, -2.0),
+        )));
+        // the first pivot is zero
+        assert_identity(SquareMatrix::from((
+            (0.0, 2.0, 1.0, -1.0),
+            (3.0, 0.5, 0.0, 2.0),
+            (1.0, 1.0, 4.0, 0.0),
+            (-2.0, 0.0, 1.0, 1.5),
+        )));
+        assert_eq!(
+            SquareMatrix::from(((1.0, 2.0), (3.0, 4.0))).inverse(),
+            Some(SquareMatrix::from(((-2.0, 1.0), (1.5, -0.5))))
+        );
+
+        assert_eq!(
+            SquareMatrix::<f64, 2>::from(((1.0, 2.0), (2.0, 4.0))).inverse(),
+            None
+        );
+        let singular =
+            SquareMatrix::<f64, 3>::from(((1.0, 2.0, 3.0), (4.0, 5.0, 6.0), (7.0, 8.0, 9.0)));
+        assert_eq!(singular.inverse(), None);
+        let singular = SquareMatrix::<f64, 4>::from((
+            (1.0, 2.0, 0.0, 1.0),
+            (0.0, 1.0, 3.0, 2.0),
+            (2.0, 4.0, 0.0, 2.0),
+            (1.0, 0.0, 1.0, 1.0),
+        ));
+        assert_eq!(singular.inverse(), None);
+    }
+
+    #[test]
+    fn trace() {
+        let mat = SquareMatrix::<i32, 3>::from(((4, 1, 7), (-2, 5, 0), (3, 8, -6)));
+        assert_eq!(mat.trace(), 3);
+        let mat = SquareMatrix::<f64, 3>::from(((0.5, 9.0, 9.0), (9.0, 1.5, 9.0), (9.0, 9.0, 2.0)));
+        assert_eq!(mat.trace(), 4.0);
+        assert_eq!(mat.transpose().trace(), mat.trace());
+    }
+
+    #[test]
+    fn trace_of_power() {
+        // eigenvalues 2 - √2, 2 and 2 + √2
+        let mat =
+            SquareMatrix::<f64, 3>::from(((2.0, -1.0, 0.0), (-1.0, 2.0, -1.0), (0.0, -1.0, 2.0)));
+        let sqrt2 = 2f64.sqrt();
+        let eigenvalues = [2.0 - sqrt2, 2.0, 2.0 + sqrt2];
+        let squares: f64 = eigenvalues.iter().map(|e| e * e).sum();
+        assert!((mat.trace_of_power(2) - squares).abs() < 1e-12);
+        let fifth_powers: f64 = eigenvalues.iter().map(|e| e.powi(5)).sum();
+        assert!((mat.trace_of_power(5) - fifth_powers).abs() < 1e-9);
+        assert_eq!(mat.trace_of_power(1), mat.trace());
+        assert_eq!(mat.trace_of_power(0), 3.0);
+
+        // a triangle has 6 closed walks of length 3 and none of length 1
+        let triangle = SquareMatrix::<i32, 3>::from(((0, 1, 1), (1, 0, 1), (1, 1, 0)));
+        assert_eq!(triangle.trace_of_power(1), 0);
+        assert_eq!(triangle.trace_of_power(3), 6);
+    }
+
+    #[test]
+    fn transpose_mul() {
+        let a = Matrix::<i32, 3, 2>::new([[1, -2, 4], [0, 3, 5]]);
+        let b = Matrix::<i32, 3, 4>::new([[2, 1, 0], [-1, 0, 3], [4, 4, -2], [1, 1, 1]]);
+        assert_eq!(a.transpose_mul(&b), a.transpose() * b);
+        assert_eq!(a.transpose_mul(&a), a.gram());
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_transpose_mul() {
+        let mut a = Matrix::<f64, 32, 16>::default();
+        let mut b = Matrix::<f64, 32, 16>::default();
+        for m in 0..32 {
+            for n in 0..16 {
+                a[[m, n]] = (m * 16 + n) as f64 * 0.01;
+                b[[m, n]] = 1.0 - (m + n) as f64 * 0.02;
+            }
+        }
+        let iterations = 20_000;
+
+        let start = std::time::Instant::now();
+        for _ in 0..iterations {
+            std::hint::black_box(std::hint::black_box(a).transpose() * b);
+        }
+        let transposed = start.elapsed();
+
+        let start = std::time::Instant::now();
+        for _ in 0..iterations {
+            std::hint::black_box(std::hint::black_box(a).transpose_mul(&b));
+        }
+        let fused = start.elapsed();
+
+        println!(
+            "transpose_mul: {:?}, transpose and multiply: {:?}",
+            fused, transposed
+        );
+    }
+
+    #[test]
+    fn gram_lower_upper() {
+        let a = Matrix::<i32, 3, 2>::new([[1, -2, 4], [0, 3, 5]]);
+        let lower = a.gram_lower();
+        let upper = a.gram_upper();
+        assert_eq!(lower, a.transpose() * a);
+        assert_eq!(upper, a * a.transpose());
+        assert_eq!(lower, lower.transpose());
+        assert_eq!(upper, upper.transpose());
+        // both share the nonzero eigenvalues, so the traces match
+        assert_eq!(lower.trace(), upper.trace());
+    }
+
+    #[test]
+    fn permute() {
+        let mat = Matrix::<i32, 3, 2>::new([[1, 2, 3], [4, 5, 6]]);
+        let reversed = mat.permute_rows(&[2, 1, 0]);
+        assert_eq!(reversed, Matrix::new([[3, 2, 1], [6, 5, 4]]));
+        assert_eq!(reversed.permute_rows(&[2, 1, 0]), mat);
+        let swapped = mat.permute_columns(&[1, 0]);
+        assert_eq!(swapped, Matrix::new([[4, 5, 6], [1, 2, 3]]));
+        assert_eq!(swapped.permute_columns(&[1, 0]), mat);
+
+        // the inverse of a cycle
+        let cycled = mat.permute_rows(&[1, 2, 0]);
+        assert_eq!(cycled[[0, 0]], 2);
+        assert_eq!(cycled.permute_rows(&[2, 0, 1]), mat);
+
+        // apply the pivots of an LU decomposition to the right hand side
+        let a = SquareMatrix::<f64, 2>::from(((0.0, 1.0), (2.0, 3.0)));
+        let lu = a.lu().unwrap();
+        let b = Vector::from([5.0, 7.0]);
+        assert_eq!(b.permute_rows(&lu.pivots), Vector::from([7.0, 5.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "not a permutation")]
+    fn permute_invalid() {
+        Matrix::<i32, 3, 1>::new([[1, 2, 3]]).permute_rows(&[0, 0, 1]);
+    }
+
+    #[test]
+    fn determinant_bareiss() {
+        let mats = [
+            SquareMatrix::new([[2, -3, 1], [2, 0, -1], [1, 4, 5]]),
+            SquareMatrix::new([[0, 1, 2], [3, 4, 5], [6, 7, 9]]),
+            SquareMatrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]),
+            SquareMatrix::new([[0, 0, 1], [0, 1, 0], [1, 0, 0]]),
+        ];
+        for mat in mats.iter() {
+            assert_eq!(mat.determinant_bareiss(), mat.determinant());
+        }
+
+        let mat = SquareMatrix::new([[3, 2, 0, 1], [4, 0, 1, 2], [3, 0, 2, 1], [9, 2, 3, 1]]);
+        assert_eq!(mat.determinant_bareiss(), 24);
+    }
+
+    #[test]
+    fn determinant_2x2() {
+        // rows 1 2 and 3 4
+        let mat = SquareMatrix::<i32, 2>::from(((1, 2), (3, 4)));
+        assert_eq!(mat.determinant(), -2);
+        assert_eq!(mat.determinant(), mat.determinant_bareiss());
+        assert_eq!(mat.transpose().determinant(), -2);
+
+        // no sign or ordering needed
+        let unsigned = SquareMatrix::<u32, 2>::from(((4, 2), (1, 3)));
+        assert_eq!(unsigned.determinant(), 10);
+    }
+
+    #[test]
+    fn determinant_lu() {
+        let mat =
+            SquareMatrix::<f64, 3>::new([[2.0, -3.0, 1.0], [2.0, 0.5, -1.0], [1.0, 4.0, 5.0]]);
+        let general = mat.lu().unwrap().determinant();
+        assert!((mat.determinant() - general).abs() < 1e-12);
+        assert!((mat.determinant() - mat.determinant_bareiss()).abs() < 1e-12);
+
+        let mat = SquareMatrix::<f64, 4>::new([
+            [3.0, 2.0, 0.0, 1.0],
+            [4.0, 0.0, 1.0, 2.0],
+            [3.0, 0.0, 2.0, 1.0],
+            [9.0, 2.0, 3.0, 1.0],
+        ]);
+        assert!((mat.determinant_lu() - 24.0).abs() < 1e-12);
+
+        let singular = SquareMatrix::<f64, 4>::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 4.0, 6.0, 8.0],
+            [0.0, 1.0, 0.0, 1.0],
+            [5.0, 0.0, 1.0, 2.0],
+        ]);
+        assert_eq!(singular.determinant_lu(), 0.0);
+    }
+
+    #[test]
+    fn determinant_float_pivoting() {
+        // regular and well conditioned, but a fraction-free elimination without pivoting on
+        // the tiny leading entry loses everything
+        let mat = SquareMatrix::<f64, 4>::from((
+            (1e-17, 1.0, 2.0, 3.0),
+            (1.0, 1.0, 0.5, 0.0),
+            (2.0, 0.3, 1.0, 0.7),
+            (0.1, 3.0, 0.2, 1.0),
+        ));
+        let expected = mat.determinant_laplace();
+        assert!((expected + 6.59).abs() < 0.01);
+        assert!((mat.determinant() - expected).abs() < 1e-12);
+        assert_eq!(mat.determinant(), mat.determinant_lu());
+
+        // the products of the bareiss algorithm overflow
+        let mut scaled = mat;
+        for m in 0..4 {
+            for n in 0..4 {
+                scaled[[m, n]] *= 1e75;
+            }
+        }
+        let det = scaled.determinant();
+        assert!(det.is_finite());
+        assert!((det / 1e300 - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn determinant_laplace() {
+        let mats = [
+            SquareMatrix::new([[2, -3, 1], [2, 0, -1], [1, 4, 5]]),
+            SquareMatrix::new([[0, 1, 2], [3, 4, 5], [6, 7, 9]]),
+            SquareMatrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]),
+        ];
+        for mat in mats.iter() {
+            assert_eq!(mat.determinant_laplace(), mat.determinant());
+        }
+
+        let mat = SquareMatrix::new([[3, 2, 0, 1], [4, 0, 1, 2], [3, 0, 2, 1], [9, 2, 3, 1]]);
+        assert_eq!(mat.determinant_laplace(), 24);
+        assert_eq!(mat.determinant(), 24);
+
+        let mat = SquareMatrix::new([
+            [2, 0, 0, 0, 0],
+            [1, 3, 0, 0, 0],
+            [4, -1, -1, 0, 0],
+            [0, 5, 2, 4, 0],
+            [7, 1, 1, 3, 5],
+        ]);
+        assert_eq!(mat.determinant_laplace(), -120);
+        assert_eq!(mat.determinant_laplace(), mat.determinant_bareiss());
+    }
+
+    #[test]
+    fn checked_sub() {
+        let a = Matrix::<u32, 2, 2>::new([[5, 3], [2, 7]]);
+        let b = Matrix::<u32, 2, 2>::new([[1, 3], [2, 4]]);
+        assert_eq!(a.checked_sub(&b), Some(Matrix::new([[4, 0], [0, 3]])));
+
+        let b = Matrix::<u32, 2, 2>::new([[1, 4], [2, 4]]);
+        assert_eq!(a.checked_sub(&b), None);
+    }
+
+    #[test]
+    fn row_col_major() {
+        // 1 2 3
+        // 4 5 6
+        let mat = Matrix::<i32, 2, 3>::new([[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(mat.to_row_major(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(mat.to_col_major(), vec![1, 4, 2, 5, 3, 6]);
+        assert_ne!(mat.to_row_major(), mat.to_col_major());
+    }
+
+    #[test]
+    fn mul_diagonal() {
+        let mat = Matrix::<i32, 2, 3>::new([[1, 4], [2, 5], [3, 6]]);
+        let diag = Vector::from([2, -1, 3]);
+        let full = SquareMatrix::new([[2, 0, 0], [0, -1, 0], [0, 0, 3]]);
+        assert_eq!(mat.mul_diagonal(&diag), mat * full);
+        assert_eq!(mat.mul_diagonal(&Vector::from([1, 1, 1])), mat);
+    }
+
+    #[test]
+    fn characteristic_polynomial() {
+        // λ² - 5λ - 2
+        let mat = SquareMatrix::<i32, 2>::new([[1, 3], [2, 4]]);
+        assert_eq!(mat.characteristic_polynomial(), vec![1, -5, -2]);
+
+        // λ³ - 6λ² + 11λ - 6 = (λ - 1)(λ - 2)(λ - 3)
+        let mat = SquareMatrix::<i32, 3>::new([[1, 0, 0], [5, 2, 0], [-1, 4, 3]]);
+        assert_eq!(mat.characteristic_polynomial(), vec![1, -6, 11, -6]);
+
+        // λ³ - 3λ² - 9λ - 5 = (λ + 1)²(λ - 5)
+        let mat = SquareMatrix::<f64, 3>::new([[1.0, 2.0, 2.0], [2.0, 1.0, 2.0], [2.0, 2.0, 1.0]]);
+        assert_eq!(mat.characteristic_polynomial(), vec![1.0, -3.0, -9.0, -5.0]);
+    }
+
+    #[test]
+    fn inverse_neumann() {
+        let mat =
+            SquareMatrix::<f64, 3>::new([[1.1, 0.05, -0.1], [0.0, 0.9, 0.02], [0.1, -0.03, 1.05]]);
+        let inverse = mat.inverse_lu().unwrap();
+        let error = |approximation: SquareMatrix<f64, 3>| {
+            let mut error = 0.0f64;
+            for m in 0..3 {
+                for n in 0..3 {
+                    error = error.max((approximation[[m, n]] - inverse[[m, n]]).abs());
+                }
+            }
+            error
+        };
+
+        assert_eq!(mat.inverse_neumann(1), SquareMatrix::mul_identity());
+        let errors: Vec<f64> = [2, 4, 8, 16, 32]
+            .iter()
+            .map(|&terms| error(mat.inverse_neumann(terms)))
+            .collect();
+        for pair in errors.windows(2) {
+            assert!(pair[1] < pair[0]);
+        }
+        assert!(errors[4] < 1e-12);
+    }
+
+    #[test]
+    fn zero_sized() {
+        let empty = SquareMatrix::<i32, 0>::new([]);
+        assert_eq!(empty.determinant(), 1);
+        assert_eq!(empty.determinant_bareiss(), 1);
+        assert_eq!(empty.determinant_laplace(), 1);
+        assert_eq!(empty.trace(), 0);
+        assert_eq!(SquareMatrix::<i32, 0>::mul_identity(), empty);
+        assert_eq!(empty * empty, empty);
+        assert_eq!(empty.characteristic_polynomial(), vec![1]);
+        assert!(empty.as_slice().is_empty());
+    }
+
+    #[test]
+    fn resize() {
+        let rotation = SquareMatrix::<i32, 3>::new([[0, 1, 0], [-1, 0, 0], [0, 0, 1]]);
+        let mut transform: SquareMatrix<i32, 4> = rotation.resize(0);
+        transform[[3, 3]] = 1;
+        assert_eq!(
+            transform,
+            SquareMatrix::new([[0, 1, 0, 0], [-1, 0, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]])
+        );
+        assert_eq!(transform.resize::<3, 3>(7), rotation);
+        assert_eq!(
+            rotation.resize::<2, 4>(9),
+            Matrix::new([[0, 1], [-1, 0], [0, 0], [9, 9]])
+        );
+    }
+
+    #[test]
+    fn map_into() {
+        let mat = Matrix::<f64, 2, 2>::new([[0.2, 1.5], [-3.0, 0.9]]);
+        let mask = mat.map_into(|e| e > 0.5);
+        assert_eq!(mask, Matrix::new([[false, true], [false, true]]));
+    }
+
+    #[test]
+    fn finite_nan() {
+        let mut mat = Matrix::<f64, 2, 2>::new([[1.0, -2.5], [1e300, 0.0]]);
+        assert!(mat.is_finite());
+        assert!(!mat.has_nan());
+
+        mat[[0, 1]] *= 1e300;
+        assert!(!mat.is_finite());
+        assert!(!mat.has_nan());
+
+        let vec = Vector::from([0.0f32, f32::NAN, 1.0]);
+        assert!(vec.has_nan());
+        assert!(!vec.is_finite());
+    }
+
+    #[test]
+    fn reduce() {
+        let mat = Matrix::<i32, 2, 3>::new([[4, -1], [2, 7], [-3, 5]]);
+        let max = |slice: &[i32]| *slice.iter().max().unwrap();
+
+        let mut col_max = vec![i32::MIN; 3];
+        let mut row_max = vec![i32::MIN; 2];
+        for m in 0..2 {
+            for n in 0..3 {
+                col_max[n] = col_max[n].max(mat[[m, n]]);
+                row_max[m] = row_max[m].max(mat[[m, n]]);
+            }
+        }
+        assert_eq!(mat.reduce_cols(max), col_max);
+        assert_eq!(mat.reduce_rows(max), row_max);
+        assert_eq!(
+            mat.reduce_rows(|row| row.to_vec()),
+            vec![vec![4, 2, -3], vec![-1, 7, 5]]
+        );
+    }
+
+    #[test]
+    fn transpose() {
+        let mat = Matrix::<i32, 3, 2>::new([[1, 2, 3], [4, 5, 6]]);
+        let transposed: Matrix<i32, 2, 3> = mat.transpose();
+        assert_eq!(transposed, Matrix::new([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(transposed.transpose(), mat);
+        for m in 0..3 {
+            for n in 0..2 {
+                assert_eq!(transposed[[n, m]], mat[[m, n]]);
+            }
+        }
+
+        let mut square = SquareMatrix::<i32, 3>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let expected = square.transpose();
+        square.transpose_square();
+        assert_eq!(square, expected);
+        assert_eq!(square, SquareMatrix::new([[1, 4, 7], [2, 5, 8], [3, 6, 9]]));
+    }
+
+    #[test]
+    fn gram() {
+        let mat = Matrix::<f64, 3, 2>::new([[1.0, 3.0, -2.0], [4.0, 0.5, 2.0]]);
+        let transposed = Matrix::<f64, 2, 3>::new([[1.0, 4.0], [3.0, 0.5], [-2.0, 2.0]]);
+        let gram = mat.gram();
+        assert_eq!(gram, transposed * mat);
+        assert_eq!(gram[[0, 1]], gram[[1, 0]]);
+    }
 }
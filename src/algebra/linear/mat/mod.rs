@@ -1,4 +1,19 @@
+pub mod equations;
+pub mod lu;
 pub mod mat;
+pub mod qr;
+pub mod rref;
 pub mod set;
+#[cfg(feature = "simd")]
+pub mod simd;
+pub mod small;
+pub mod solve;
+pub mod svd;
+pub use equations::{solve_lyapunov, solve_sylvester};
+pub use lu::{Lu, Pivoting, Sign};
+pub use mat::DeterminantAlgorithm;
 pub use mat::Matrix;
 pub use mat::SquareMatrix;
+pub use qr::gram_schmidt_qr;
+pub use small::{det2, det3, inv2, inv3};
+pub use solve::SolveMethod;
@@ -0,0 +1,235 @@
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::{DMatrix, Matrix, SquareMatrix, Vector};
+use fructose::operators::ClosedOps;
+use fructose::specific::complex::Real;
+
+impl<T: Scalar + ClosedOps + Real, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+    /// QR decomposition via modified gram-schmidt, `Q` has orthonormal columns and `R` is
+    /// upper triangular. requires `M >= N` and linearly independent columns
+    pub fn qr(&self) -> (Matrix<T, { M }, { N }>, SquareMatrix<T, { N }>) {
+        let mut q = *self;
+        let mut r = SquareMatrix::default();
+
+        for k in 0..N {
+            let mut norm = T::zero();
+            for m in 0..M {
+                norm += q[[m, k]] * q[[m, k]];
+            }
+            let norm = norm.sqrt();
+            r[[k, k]] = norm;
+            for m in 0..M {
+                q[[m, k]] /= norm;
+            }
+
+            for j in k + 1..N {
+                let mut dot = T::zero();
+                for m in 0..M {
+                    dot += q[[m, k]] * q[[m, j]];
+                }
+                r[[k, j]] = dot;
+                for m in 0..M {
+                    let sub = dot * q[[m, k]];
+                    q[[m, j]] -= sub;
+                }
+            }
+        }
+
+        (q, r)
+    }
+
+    /// full QR decomposition via householder reflections, `Q` is orthogonal and `R` is upper
+    /// triangular. unlike `qr` the orthogonality of `Q` doesn't degrade for ill-conditioned
+    /// matrices and any shape or rank is supported
+    pub fn qr_householder(&self) -> (SquareMatrix<T, { M }>, Matrix<T, { M }, { N }>) {
+        let mut q = SquareMatrix::<T, { M }>::default();
+        for m in 0..M {
+            q[[m, m]] = T::one();
+        }
+        let mut r = *self;
+        let two = T::one() + T::one();
+
+        let steps = if M > N { N } else { M.saturating_sub(1) };
+        for k in 0..steps {
+            let mut norm = T::zero();
+            for m in k..M {
+                norm += r[[m, k]] * r[[m, k]];
+            }
+            let norm = norm.sqrt();
+            if norm.is_zero() {
+                continue;
+            }
+
+            // reflect onto -sign(r_kk) * norm * e_k to avoid cancellation in v
+            let alpha = if r[[k, k]] < T::zero() { norm } else { -norm };
+            let mut v = [T::zero(); M];
+            for m in k..M {
+                v[m] = r[[m, k]];
+            }
+            v[k] -= alpha;
+            let mut v_norm = T::zero();
+            for e in v[k..].iter() {
+                v_norm += *e * *e;
+            }
+
+            // R = H R and Q = Q H with H = I - 2 v vᵀ / (vᵀ v)
+            for n in k..N {
+                let mut dot = T::zero();
+                for m in k..M {
+                    dot += v[m] * r[[m, n]];
+                }
+                let factor = two * dot / v_norm;
+                for m in k..M {
+                    let sub = factor * v[m];
+                    r[[m, n]] -= sub;
+                }
+            }
+            for i in 0..M {
+                let mut dot = T::zero();
+                for m in k..M {
+                    dot += q[[i, m]] * v[m];
+                }
+                let factor = two * dot / v_norm;
+                for m in k..M {
+                    let sub = factor * v[m];
+                    q[[i, m]] -= sub;
+                }
+            }
+
+            r[[k, k]] = alpha;
+            for m in k + 1..M {
+                r[[m, k]] = T::zero();
+            }
+        }
+
+        (q, r)
+    }
+}
+
+/// orthonormalizes `vectors` with modified gram-schmidt and returns the orthonormal set `Q`
+/// together with the upper triangular `k x k` coefficients `R`, so that
+/// `vectors[j] = Σ_i R[(i, j)] * Q[i]`. the vectors have to be linearly independent
+pub fn gram_schmidt_qr<T: Scalar + ClosedOps + Real, const N: usize>(
+    vectors: &[Vector<T, { N }>],
+) -> (Vec<Vector<T, { N }>>, DMatrix<T>) {
+    let len = vectors.len();
+    let mut q = vectors.to_vec();
+    let mut r = DMatrix::new(vec![vec![T::zero(); len]; len]);
+
+    for k in 0..len {
+        let norm = q[k].dot(q[k]).sqrt();
+        r[(k, k)] = norm;
+        q[k] /= norm;
+
+        for j in k + 1..len {
+            let dot = q[k].dot(q[j]);
+            r[(k, j)] = dot;
+            let mut sub = q[k];
+            sub *= dot;
+            q[j] -= sub;
+        }
+    }
+
+    (q, r)
+}
+
+#[cfg(test)]
+mod qr_tests {
+    use crate::algebra::linear::{gram_schmidt_qr, Matrix, SquareMatrix, Vector};
+
+    fn max_error<const M: usize, const N: usize>(
+        a: Matrix<f64, M, N>,
+        b: Matrix<f64, M, N>,
+    ) -> f64 {
+        let mut error = 0.0f64;
+        for m in 0..M {
+            for n in 0..N {
+                error = error.max((a[[m, n]] - b[[m, n]]).abs());
+            }
+        }
+        error
+    }
+
+    #[test]
+    fn qr() {
+        let mat = Matrix::<f64, 3, 2>::new([[12.0, 6.0, -4.0], [-51.0, 167.0, 24.0]]);
+        let (q, r) = mat.qr();
+
+        assert_eq!(r[[1, 0]], 0.0);
+        let reconstructed = q * r;
+        let gram = q.gram();
+        for m in 0..3 {
+            for n in 0..2 {
+                assert!((reconstructed[[m, n]] - mat[[m, n]]).abs() < 1e-12);
+            }
+        }
+        for m in 0..2 {
+            for n in 0..2 {
+                let expected = if m == n { 1.0 } else { 0.0 };
+                assert!((gram[[m, n]] - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn gram_schmidt_qr_vectors() {
+        let vectors = [
+            Vector::<f64, 3>::from([1.0, 1.0, 0.0]),
+            Vector::from([1.0, 0.0, 1.0]),
+            Vector::from([0.0, 1.0, 1.0]),
+        ];
+        let (q, r) = gram_schmidt_qr(&vectors);
+        assert_eq!(q.len(), 3);
+        assert_eq!(r.size, (3, 3));
+
+        assert_eq!(r[(2, 0)], 0.0);
+        for (j, original) in vectors.iter().enumerate() {
+            let mut reconstructed = Vector::<f64, 3>::default();
+            for (i, q) in q.iter().enumerate() {
+                let mut scaled = *q;
+                scaled *= r[(i, j)];
+                reconstructed += scaled;
+            }
+            for n in 0..3 {
+                assert!((reconstructed[n] - original[n]).abs() < 1e-12);
+            }
+            for (i, other) in q.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((q[j].dot(*other) - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn qr_householder() {
+        let mat = Matrix::<f64, 3, 2>::new([[12.0, 6.0, -4.0], [-51.0, 167.0, 24.0]]);
+        let (q, r) = mat.qr_householder();
+        assert_eq!(r[[1, 0]], 0.0);
+        assert_eq!(r[[2, 1]], 0.0);
+        assert!(max_error(q * r, mat) < 1e-12);
+        assert!(max_error(q.gram(), SquareMatrix::mul_identity()) < 1e-12);
+    }
+
+    #[test]
+    fn qr_householder_hilbert() {
+        // the hilbert matrix has a condition number of about 1.5e7
+        let mut hilbert = SquareMatrix::<f64, 6>::default();
+        for m in 0..6 {
+            for n in 0..6 {
+                hilbert[[m, n]] = 1.0 / (m + n + 1) as f64;
+            }
+        }
+
+        let (q, r) = hilbert.qr_householder();
+        let (q_gs, r_gs) = hilbert.qr();
+        let error = max_error(q * r, hilbert);
+        let error_gs = max_error(q_gs * r_gs, hilbert);
+        let orthogonality = max_error(q.gram(), SquareMatrix::mul_identity());
+        let orthogonality_gs = max_error(q_gs.gram(), SquareMatrix::mul_identity());
+        // both reconstruct the matrix up to rounding errors,
+        // but gram-schmidt loses orthogonality proportional to the condition number
+        assert!(error < 1e-14);
+        assert!(error_gs < 1e-14);
+        assert!(orthogonality < 1e-14);
+        assert!(orthogonality * 1e4 < orthogonality_gs);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::{Matrix, Vector};
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::float::Float;
+use fructose::specific::complex::Real;
+
+impl<T: Scalar + ClosedOps + Real + Float, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+    /// reduced row echelon form via gauss-jordan elimination with partial pivoting,
+    /// together with the pivot column of every nonzero row. elements below
+    /// `max(M, N) * EPSILON * max |a_ij|` are treated as zero
+    pub fn rref(&self) -> (Self, Vec<usize>) {
+        let mut mat = *self;
+        let mut largest = T::zero();
+        let mut scale = T::zero();
+        for m in 0..M {
+            scale += T::one();
+            for n in 0..N {
+                if mat[[m, n]].abs() > largest {
+                    largest = mat[[m, n]].abs();
+                }
+            }
+        }
+        for _ in M..N {
+            scale += T::one();
+        }
+        let tolerance = scale * T::EPSILON * largest;
+
+        let mut pivots = Vec::new();
+        let mut row = 0;
+        for col in 0..N {
+            if row == M {
+                break;
+            }
+
+            let mut pivot = row;
+            for m in row + 1..M {
+                if mat[[m, col]].abs() > mat[[pivot, col]].abs() {
+                    pivot = m;
+                }
+            }
+            if mat[[pivot, col]].abs() <= tolerance {
+                for m in row..M {
+                    mat[[m, col]] = T::zero();
+                }
+                continue;
+            }
+
+            for n in 0..N {
+                mat.data[n].swap(pivot, row);
+            }
+
+            let divisor = mat[[row, col]];
+            for n in col..N {
+                mat[[row, n]] /= divisor;
+            }
+            for m in 0..M {
+                if m == row {
+                    continue;
+                }
+                let factor = mat[[m, col]];
+                for n in col..N {
+                    let sub = factor * mat[[row, n]];
+                    mat[[m, n]] -= sub;
+                }
+            }
+
+            pivots.push(col);
+            row += 1;
+        }
+
+        (mat, pivots)
+    }
+
+    /// basis of the null space (kernel), one vector per free column of the rref
+    pub fn null_space(&self) -> Vec<Vector<T, { N }>> {
+        let (rref, pivots) = self.rref();
+        (0..N)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                let mut vec = Vector::default();
+                vec[free] = T::one();
+                for (row, &pivot) in pivots.iter().enumerate() {
+                    vec[pivot] = -rref[[row, free]];
+                }
+                vec
+            })
+            .collect()
+    }
+
+    /// basis of the column space (range), the columns of `self` at the pivot positions
+    /// of the rref
+    pub fn column_space(&self) -> Vec<Vector<T, { M }>> {
+        let (_, pivots) = self.rref();
+        pivots
+            .into_iter()
+            .map(|col| Vector::from(self.data[col]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod rref_tests {
+    use crate::algebra::linear::{Matrix, Vector};
+
+    #[test]
+    fn rref() {
+        let mat = Matrix::<f64, 3, 4>::new([
+            [1.0, 2.0, 1.0],
+            [2.0, 4.0, 2.0],
+            [0.0, 1.0, 1.0],
+            [1.0, 3.0, 2.0],
+        ]);
+        let (rref, pivots) = mat.rref();
+        assert_eq!(pivots, vec![0, 2]);
+        let expected = Matrix::new([
+            [1.0, 0.0, 0.0],
+            [2.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [1.0, 1.0, 0.0],
+        ]);
+        for m in 0..3 {
+            for n in 0..4 {
+                assert!((rref[[m, n]] - expected[[m, n]]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn null_space() {
+        // rank 2, the second column is twice the first and the fourth is the sum
+        // of the first and the third
+        let mat = Matrix::<f64, 3, 4>::new([
+            [1.0, 2.0, 1.0],
+            [2.0, 4.0, 2.0],
+            [0.0, 1.0, 1.0],
+            [1.0, 3.0, 2.0],
+        ]);
+        let null_space = mat.null_space();
+        assert_eq!(null_space.len(), 2);
+
+        let expected = [
+            Vector::from([-2.0, 1.0, 0.0, 0.0]),
+            Vector::from([-1.0, 0.0, -1.0, 1.0]),
+        ];
+        for (vec, expected) in null_space.iter().zip(expected.iter()) {
+            let product = mat * *vec;
+            for m in 0..3 {
+                assert!(product[m].abs() < 1e-12);
+            }
+            for n in 0..4 {
+                assert!((vec[n] - expected[n]).abs() < 1e-12);
+            }
+        }
+
+        let regular = Matrix::<f64, 2, 2>::new([[2.0, 1.0], [1.0, 3.0]]);
+        assert!(regular.null_space().is_empty());
+    }
+
+    #[test]
+    fn column_space() {
+        let mat = Matrix::<f64, 3, 4>::new([
+            [1.0, 2.0, 1.0],
+            [2.0, 4.0, 2.0],
+            [0.0, 1.0, 1.0],
+            [1.0, 3.0, 2.0],
+        ]);
+        let column_space = mat.column_space();
+        assert_eq!(column_space.len(), 2);
+        assert_eq!(column_space[0], Vector::from([1.0, 2.0, 1.0]));
+        assert_eq!(column_space[1], Vector::from([0.0, 1.0, 1.0]));
+
+        // the basis is linearly independent, so it has full column rank itself
+        let basis = Matrix::<f64, 3, 2>::new([column_space[0].data[0], column_space[1].data[0]]);
+        assert!(basis.null_space().is_empty());
+        assert_eq!(column_space.len() + mat.null_space().len(), 4);
+    }
+}
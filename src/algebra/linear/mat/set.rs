@@ -81,15 +81,22 @@ impl<T: Scalar + ClosedAdd + ClosedMul + Commutative<Multiplicative>, const N: u
 {
 }
 
-impl<T: Scalar + ClosedAdd + ClosedMul + Identity<Multiplicative> + PartialEq, const N: usize>
-    Identity<Multiplicative> for SquareMatrix<T, { N }>
+impl<
+        T: Scalar + ClosedAdd + ClosedMul + Identity<Multiplicative> + Identity<Additive> + PartialEq,
+        const N: usize,
+    > Identity<Multiplicative> for SquareMatrix<T, { N }>
 {
+    /// the identity matrix
     fn identity() -> Self {
-        Self::broadcast(T::identity())
+        let mut mat = Self::broadcast(<T as Identity<Additive>>::identity());
+        for n in 0..N {
+            mat[[n, n]] = <T as Identity<Multiplicative>>::identity();
+        }
+        mat
     }
 
     fn is_identity(&self) -> bool {
-        *self == Self::identity()
+        *self == <Self as Identity<Multiplicative>>::identity()
     }
 }
 
@@ -196,3 +203,23 @@ impl<T: Scalar + ClosedOps + ComplexField<RealField = T> + Norm, const N: usize>
         *self - *rhs
     }
 }
+
+#[cfg(test)]
+mod set_tests {
+    use crate::algebra::linear::{Matrix, SquareMatrix};
+    use fructose::properties::helpers::identity::{One, Zero};
+
+    #[test]
+    fn zero_one() {
+        let mat = Matrix::<i32, 2, 3>::new([[1, -2], [3, 4], [0, 7]]);
+        assert_eq!(mat + Matrix::zero(), mat);
+        assert!(Matrix::<i32, 2, 3>::zero().is_zero());
+
+        let square = SquareMatrix::<i32, 3>::new([[1, -2, 5], [3, 4, 0], [0, 7, 2]]);
+        let one = SquareMatrix::one();
+        assert_eq!(square * one, square);
+        assert_eq!(one * square, square);
+        assert_eq!(one, SquareMatrix::mul_identity());
+        assert!(one.is_one());
+    }
+}
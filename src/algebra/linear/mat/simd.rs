@@ -0,0 +1,83 @@
+use crate::algebra::linear::SquareMatrix;
+use std::simd::f32x4;
+
+impl SquareMatrix<f32, 4> {
+    /// matrix multiplication processing each column of the result as one simd vector.
+    /// every element is accumulated in the same order as the scalar `Mul` implementation
+    /// and no fused multiply-add is used, so results are bit-identical for finite inputs.
+    /// (switching to fma would round differently)
+    #[inline]
+    pub fn mul_simd(&self, rhs: &Self) -> Self {
+        let columns = [
+            f32x4::from_array(self.data[0]),
+            f32x4::from_array(self.data[1]),
+            f32x4::from_array(self.data[2]),
+            f32x4::from_array(self.data[3]),
+        ];
+
+        let mut mat = Self::default();
+        for (p, rhs_column) in rhs.data.iter().enumerate() {
+            let mut column = f32x4::splat(0.0);
+            for (n, lhs_column) in columns.iter().enumerate() {
+                column += *lhs_column * f32x4::splat(rhs_column[n]);
+            }
+            mat.data[p] = column.to_array();
+        }
+        mat
+    }
+}
+
+#[cfg(test)]
+mod simd_tests {
+    use crate::algebra::linear::SquareMatrix;
+    use std::time::Instant;
+
+    fn matrices() -> (SquareMatrix<f32, 4>, SquareMatrix<f32, 4>) {
+        let mut a = SquareMatrix::default();
+        let mut b = SquareMatrix::default();
+        for m in 0..4 {
+            for n in 0..4 {
+                a[[m, n]] = (m as f32 * 1.3 - n as f32 * 0.7).sin();
+                b[[m, n]] = (m as f32 * 0.4 + n as f32 * 2.1).cos() * 3.0;
+            }
+        }
+        (a, b)
+    }
+
+    #[test]
+    fn mul_simd() {
+        let (a, b) = matrices();
+        let scalar = a * b;
+        let simd = a.mul_simd(&b);
+        for m in 0..4 {
+            for n in 0..4 {
+                assert_eq!(scalar[[m, n]].to_bits(), simd[[m, n]].to_bits());
+            }
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_mul_simd() {
+        let (a, b) = matrices();
+        let iterations = 10_000_000;
+
+        let start = Instant::now();
+        let mut mat = a;
+        for _ in 0..iterations {
+            mat = std::hint::black_box(mat) * b;
+        }
+        let scalar = start.elapsed();
+        std::hint::black_box(mat);
+
+        let start = Instant::now();
+        let mut mat = a;
+        for _ in 0..iterations {
+            mat = std::hint::black_box(mat).mul_simd(&b);
+        }
+        let simd = start.elapsed();
+        std::hint::black_box(mat);
+
+        println!("mul_simd: {:?}, scalar product: {:?}", simd, scalar);
+    }
+}
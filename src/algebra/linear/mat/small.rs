@@ -0,0 +1,101 @@
+//! closed form determinants and inverses of `2x2` and `3x3` `f64` arrays, usable in const
+//! contexts. the arrays can be column major like `Matrix::data` or row major, transposing
+//! doesn't change the determinant and the inverse is transposed along
+
+/// determinant of a `2x2` matrix
+#[inline]
+pub const fn det2(m: [[f64; 2]; 2]) -> f64 {
+    m[0][0] * m[1][1] - m[0][1] * m[1][0]
+}
+
+/// determinant of a `3x3` matrix, expanded along the first column
+#[inline]
+pub const fn det3(m: [[f64; 3]; 3]) -> f64 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// inverse of a `2x2` matrix, `None` if the determinant is zero
+#[inline]
+pub const fn inv2(m: [[f64; 2]; 2]) -> Option<[[f64; 2]; 2]> {
+    let det = det2(m);
+    if det == 0.0 {
+        return None;
+    }
+    Some([
+        [m[1][1] / det, -m[0][1] / det],
+        [-m[1][0] / det, m[0][0] / det],
+    ])
+}
+
+/// inverse of a `3x3` matrix as the adjugate divided by the determinant,
+/// `None` if the determinant is zero
+#[inline]
+pub const fn inv3(m: [[f64; 3]; 3]) -> Option<[[f64; 3]; 3]> {
+    let det = det3(m);
+    if det == 0.0 {
+        return None;
+    }
+    // element (i, j) of the inverse is the cofactor (j, i) divided by the determinant
+    let mut inverse = [[0.0; 3]; 3];
+    let mut i = 0;
+    while i < 3 {
+        let mut j = 0;
+        while j < 3 {
+            let (r1, r2) = ((j + 1) % 3, (j + 2) % 3);
+            let (c1, c2) = ((i + 1) % 3, (i + 2) % 3);
+            inverse[i][j] = (m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1]) / det;
+            j += 1;
+        }
+        i += 1;
+    }
+    Some(inverse)
+}
+
+#[cfg(test)]
+mod small_tests {
+    use crate::algebra::linear::{det2, det3, inv2, inv3, SquareMatrix};
+
+    const DET: f64 = det2([[1.0, 3.0], [2.0, 4.0]]);
+    const INVERSE: Option<[[f64; 2]; 2]> = inv2([[1.0, 3.0], [2.0, 4.0]]);
+
+    #[test]
+    fn const_eval() {
+        assert_eq!(DET, -2.0);
+        assert_eq!(INVERSE, Some([[-2.0, 1.5], [1.0, -0.5]]));
+    }
+
+    #[test]
+    fn matches_generic() {
+        let mat = SquareMatrix::<f64, 2>::new([[1.5, -2.0], [0.5, 4.0]]);
+        assert_eq!(det2(mat.data), mat.determinant());
+        let inverse = SquareMatrix::new(inv2(mat.data).unwrap());
+        let expected = mat.inverse().unwrap();
+        for m in 0..2 {
+            for n in 0..2 {
+                assert!((inverse[[m, n]] - expected[[m, n]]).abs() < 1e-12);
+            }
+        }
+
+        let mat =
+            SquareMatrix::<f64, 3>::new([[2.0, -1.0, 0.5], [0.0, 3.0, 1.0], [4.0, 1.0, -2.0]]);
+        assert!((det3(mat.data) - mat.determinant()).abs() < 1e-12);
+        let inverse = SquareMatrix::new(inv3(mat.data).unwrap());
+        let expected = mat.inverse().unwrap();
+        for m in 0..3 {
+            for n in 0..3 {
+                assert!((inverse[[m, n]] - expected[[m, n]]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn singular() {
+        assert_eq!(inv2([[1.0, 2.0], [2.0, 4.0]]), None);
+        assert_eq!(
+            inv3([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]),
+            None
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::{conjugate_gradient, DVector, SquareMatrix, Vector};
+use fructose::algebra::lattice::Lattice;
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::float::Float;
+use fructose::properties::helpers::identity::{One, Zero};
+use fructose::properties::helpers::sign::Signed;
+use fructose::specific::complex::Real;
+
+/// algorithm used by `SquareMatrix::solve_with`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum SolveMethod {
+    /// LU decomposition with partial pivoting, works for every regular matrix
+    #[default]
+    Lu,
+    /// cholesky decomposition, about twice as fast as LU but only for symmetric positive
+    /// definite matrices
+    Cholesky,
+    /// householder QR decomposition, slower than LU but more stable for ill-conditioned
+    /// matrices
+    Qr,
+    /// conjugate gradient iteration, only for symmetric positive definite matrices
+    Iterative,
+}
+
+impl<T, const N: usize> SquareMatrix<T, { N }>
+where
+    T: Scalar + ClosedOps + Signed + Lattice + Zero + One + Real + Float,
+{
+    /// cholesky decomposition `self = L * Lᵀ` with a lower triangular `L`. only the lower
+    /// triangle of `self` is read, returns `None` if `self` isn't positive definite
+    pub fn cholesky(&self) -> Option<Self> {
+        let mut l = Self::default();
+        for j in 0..N {
+            let mut diagonal = self[[j, j]];
+            for k in 0..j {
+                diagonal -= l[[j, k]] * l[[j, k]];
+            }
+            if diagonal <= T::zero() {
+                return None;
+            }
+            l[[j, j]] = diagonal.sqrt();
+
+            for i in j + 1..N {
+                let mut sum = self[[i, j]];
+                for k in 0..j {
+                    sum -= l[[i, k]] * l[[j, k]];
+                }
+                l[[i, j]] = sum / l[[j, j]];
+            }
+        }
+        Some(l)
+    }
+
+    /// solves `self * x = b` with the given method. returns `None` if `self` is singular,
+    /// isn't positive definite for `Cholesky` or the iteration doesn't converge within
+    /// `10 * N` steps for `Iterative`
+    pub fn solve_with(&self, b: Vector<T, { N }>, method: SolveMethod) -> Option<Vector<T, { N }>> {
+        match method {
+            SolveMethod::Lu => self.solve(b),
+            SolveMethod::Cholesky => self.solve_cholesky(b),
+            SolveMethod::Qr => self.solve_qr(b),
+            SolveMethod::Iterative => {
+                let b = DVector::new(b.as_slice().to_vec());
+                let x = conjugate_gradient(self, &b, T::EPSILON, 10 * N)?;
+                let mut result = Vector::default();
+                result.as_slice_mut().copy_from_slice(&x.data);
+                Some(result)
+            }
+        }
+    }
+
+    fn solve_cholesky(&self, b: Vector<T, { N }>) -> Option<Vector<T, { N }>> {
+        let l = self.cholesky()?;
+        // L * y = b
+        let mut x = b;
+        for i in 0..N {
+            for k in 0..i {
+                let sub = l[[i, k]] * x[k];
+                x[i] -= sub;
+            }
+            x[i] /= l[[i, i]];
+        }
+        // Lᵀ * x = y
+        for i in (0..N).rev() {
+            for k in i + 1..N {
+                let sub = l[[k, i]] * x[k];
+                x[i] -= sub;
+            }
+            x[i] /= l[[i, i]];
+        }
+        Some(x)
+    }
+
+    fn solve_qr(&self, b: Vector<T, { N }>) -> Option<Vector<T, { N }>> {
+        let (q, r) = self.qr_householder();
+        // R * x = Qᵀ * b
+        let mut x = q.transpose_mul(&b);
+        for i in (0..N).rev() {
+            if r[[i, i]].is_zero() {
+                return None;
+            }
+            for k in i + 1..N {
+                let sub = r[[i, k]] * x[k];
+                x[i] -= sub;
+            }
+            x[i] /= r[[i, i]];
+        }
+        Some(x)
+    }
+}
+
+#[cfg(test)]
+mod solve_tests {
+    use crate::algebra::linear::{SolveMethod, SquareMatrix, Vector};
+
+    const METHODS: [SolveMethod; 4] = [
+        SolveMethod::Lu,
+        SolveMethod::Cholesky,
+        SolveMethod::Qr,
+        SolveMethod::Iterative,
+    ];
+
+    #[test]
+    fn spd() {
+        let mat = SquareMatrix::<f64, 4>::from((
+            (4.0, 1.0, 0.5, 0.0),
+            (1.0, 5.0, -1.0, 0.5),
+            (0.5, -1.0, 6.0, 1.0),
+            (0.0, 0.5, 1.0, 3.0),
+        ));
+        let b = Vector::from([1.0, -2.0, 0.5, 3.0]);
+        let expected = mat.solve(b).unwrap();
+        for method in METHODS.iter() {
+            let x = mat.solve_with(b, *method).unwrap();
+            assert!((x - expected).norm_linf() < 1e-12, "{:?}", method);
+            assert!((mat * x - b).norm_linf() < 1e-12, "{:?}", method);
+        }
+        assert_eq!(SolveMethod::default(), SolveMethod::Lu);
+    }
+
+    #[test]
+    fn cholesky() {
+        let mat =
+            SquareMatrix::<f64, 3>::from(((4.0, 2.0, -2.0), (2.0, 10.0, 2.0), (-2.0, 2.0, 6.0)));
+        let l = mat.cholesky().unwrap();
+        let expected =
+            SquareMatrix::<f64, 3>::from(((2.0, 0.0, 0.0), (1.0, 3.0, 0.0), (-1.0, 1.0, 2.0)));
+        assert_eq!(l, expected);
+        assert_eq!(l * l.transpose(), mat);
+
+        // symmetric but indefinite
+        let indefinite = SquareMatrix::<f64, 2>::from(((1.0, 2.0), (2.0, 1.0)));
+        assert_eq!(indefinite.cholesky(), None);
+        let b = Vector::from([1.0, 1.0]);
+        assert_eq!(indefinite.solve_with(b, SolveMethod::Cholesky), None);
+        let x = indefinite.solve_with(b, SolveMethod::Qr).unwrap();
+        assert!((indefinite * x - b).norm_linf() < 1e-12);
+    }
+
+    #[test]
+    fn singular() {
+        let mat = SquareMatrix::<f64, 2>::from(((1.0, 2.0), (2.0, 4.0)));
+        let b = Vector::from([1.0, 1.0]);
+        assert_eq!(mat.solve_with(b, SolveMethod::Lu), None);
+        assert_eq!(mat.solve_with(b, SolveMethod::Cholesky), None);
+        assert_eq!(mat.solve_with(b, SolveMethod::Qr), None);
+    }
+}
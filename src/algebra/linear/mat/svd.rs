@@ -0,0 +1,236 @@
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::{Matrix, SquareMatrix, Vector};
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::float::Float;
+use fructose::specific::complex::Real;
+
+const MAX_SWEEPS: usize = 64;
+
+impl<T: Scalar + ClosedOps + Real + Float, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+    /// thin singular value decomposition `self = U * diag(S) * Vᵀ` via one-sided jacobi
+    /// rotations. requires `M >= N`, `U` has orthonormal columns, `V` is orthogonal and the
+    /// singular values are sorted in descending order
+    pub fn svd(
+        &self,
+    ) -> (
+        Matrix<T, { M }, { N }>,
+        Vector<T, { N }>,
+        SquareMatrix<T, { N }>,
+    ) {
+        assert!(M >= N, "svd requires at least as many rows as columns");
+        let mut u = *self;
+        let mut v = SquareMatrix::<T, { N }>::default();
+        for n in 0..N {
+            v[[n, n]] = T::one();
+        }
+
+        let two = T::one() + T::one();
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..N {
+                for q in p + 1..N {
+                    let mut alpha = T::zero();
+                    let mut beta = T::zero();
+                    let mut gamma = T::zero();
+                    for m in 0..M {
+                        alpha += u[[m, p]] * u[[m, p]];
+                        beta += u[[m, q]] * u[[m, q]];
+                        gamma += u[[m, p]] * u[[m, q]];
+                    }
+                    if gamma.abs() <= T::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (two * gamma);
+                    let mut t = T::one() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                    if zeta < T::zero() {
+                        t = -t;
+                    }
+                    let c = T::one() / (T::one() + t * t).sqrt();
+                    let s = c * t;
+
+                    for m in 0..M {
+                        let (a_p, a_q) = (u[[m, p]], u[[m, q]]);
+                        u[[m, p]] = c * a_p - s * a_q;
+                        u[[m, q]] = s * a_p + c * a_q;
+                    }
+                    for n in 0..N {
+                        let (v_p, v_q) = (v[[n, p]], v[[n, q]]);
+                        v[[n, p]] = c * v_p - s * v_q;
+                        v[[n, q]] = s * v_p + c * v_q;
+                    }
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut singular = Vector::<T, { N }>::default();
+        for n in 0..N {
+            let mut norm = T::zero();
+            for m in 0..M {
+                norm += u[[m, n]] * u[[m, n]];
+            }
+            singular[n] = norm.sqrt();
+        }
+
+        let mut order: Vec<usize> = (0..N).collect();
+        order.sort_by(|&a, &b| singular[b].partial_cmp(&singular[a]).unwrap());
+        let (unsorted_u, unsorted_singular, unsorted_v) = (u, singular, v);
+        for (n, &from) in order.iter().enumerate() {
+            u.data[n] = unsorted_u.data[from];
+            v.data[n] = unsorted_v.data[from];
+            singular[n] = unsorted_singular[from];
+        }
+
+        for n in 0..N {
+            if singular[n] > T::EPSILON * singular[0] {
+                for m in 0..M {
+                    u[[m, n]] /= singular[n];
+                }
+            } else {
+                // rank deficient, complete the basis with an orthonormalized unit vector
+                u.data[n] = [T::zero(); M];
+                for unit in 0..M {
+                    let mut candidate = [T::zero(); M];
+                    candidate[unit] = T::one();
+                    for k in 0..n {
+                        let mut dot = T::zero();
+                        for m in 0..M {
+                            dot += u[[m, k]] * candidate[m];
+                        }
+                        for (m, e) in candidate.iter_mut().enumerate() {
+                            *e -= dot * u[[m, k]];
+                        }
+                    }
+                    let mut norm = T::zero();
+                    for e in candidate.iter() {
+                        norm += *e * *e;
+                    }
+                    let norm = norm.sqrt();
+                    if norm > T::one() / two {
+                        for e in candidate.iter_mut() {
+                            *e /= norm;
+                        }
+                        u.data[n] = candidate;
+                        break;
+                    }
+                }
+            }
+        }
+
+        (u, singular, v)
+    }
+}
+
+impl<T: Scalar + ClosedOps + Real + Float, const N: usize> SquareMatrix<T, { N }> {
+    /// polar decomposition `self = U * P` with an orthogonal `U` and a symmetric positive
+    /// semidefinite `P`, computed from the svd as `U = W * Vᵀ` and `P = V * S * Vᵀ`
+    pub fn polar_decomposition(&self) -> (SquareMatrix<T, { N }>, SquareMatrix<T, { N }>) {
+        let (w, singular, v) = self.svd();
+        let mut u = SquareMatrix::default();
+        let mut p = SquareMatrix::default();
+        for m in 0..N {
+            for n in 0..N {
+                for k in 0..N {
+                    u[[m, n]] += w[[m, k]] * v[[n, k]];
+                    p[[m, n]] += v[[m, k]] * singular[k] * v[[n, k]];
+                }
+            }
+        }
+        (u, p)
+    }
+
+    /// nearest orthogonal matrix (in the frobenius norm), the `U` of the polar decomposition.
+    /// used to re-orthonormalize rotation matrices that drifted due to rounding errors
+    #[inline]
+    pub fn orthonormalized(&self) -> SquareMatrix<T, { N }> {
+        self.polar_decomposition().0
+    }
+}
+
+#[cfg(test)]
+mod svd_tests {
+    use crate::algebra::linear::{Matrix, SquareMatrix};
+
+    fn assert_close<const M: usize, const N: usize>(
+        a: Matrix<f64, M, N>,
+        b: Matrix<f64, M, N>,
+        epsilon: f64,
+    ) {
+        for m in 0..M {
+            for n in 0..N {
+                assert!(
+                    (a[[m, n]] - b[[m, n]]).abs() < epsilon,
+                    "{:?} != {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn svd() {
+        let mat = Matrix::<f64, 3, 2>::new([[3.0, 1.0, 1.0], [-1.0, 3.0, 1.0]]);
+        let (u, singular, v) = mat.svd();
+        assert!((singular[0] - 12.0f64.sqrt()).abs() < 1e-12);
+        assert!((singular[1] - 10.0f64.sqrt()).abs() < 1e-12);
+
+        let mut reconstructed = Matrix::<f64, 3, 2>::default();
+        for m in 0..3 {
+            for n in 0..2 {
+                for k in 0..2 {
+                    reconstructed[[m, n]] += u[[m, k]] * singular[k] * v[[n, k]];
+                }
+            }
+        }
+        assert_close(reconstructed, mat, 1e-12);
+        assert_close(u.gram(), SquareMatrix::mul_identity(), 1e-12);
+        assert_close(v.gram(), SquareMatrix::mul_identity(), 1e-12);
+    }
+
+    #[test]
+    fn polar_decomposition() {
+        let (sin, cos) = 0.7f64.sin_cos();
+        let rotation = SquareMatrix::new([[cos, sin, 0.0], [-sin, cos, 0.0], [0.0, 0.0, 1.0]]);
+        let (u, p) = rotation.polar_decomposition();
+        assert_close(u, rotation, 1e-12);
+        assert_close(p, SquareMatrix::mul_identity(), 1e-12);
+
+        let mat = SquareMatrix::<f64, 3>::new([[2.0, 0.5, 0.0], [1.0, 3.0, -1.0], [0.0, 0.2, 1.5]]);
+        let (u, p) = mat.polar_decomposition();
+        assert_close(u * p, mat, 1e-12);
+        assert_close(u.gram(), SquareMatrix::mul_identity(), 1e-12);
+        for m in 0..3 {
+            for n in 0..3 {
+                assert!((p[[m, n]] - p[[n, m]]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn polar_decomposition_singular() {
+        let mat = SquareMatrix::<f64, 2>::new([[1.0, 1.0], [1.0, 1.0]]);
+        let (u, p) = mat.polar_decomposition();
+        assert_close(u * p, mat, 1e-12);
+        assert_close(u.gram(), SquareMatrix::mul_identity(), 1e-12);
+    }
+
+    #[test]
+    fn orthonormalized() {
+        let (sin, cos) = 1.3f64.sin_cos();
+        let rotation = SquareMatrix::new([[cos, 0.0, -sin], [0.0, 1.0, 0.0], [sin, 0.0, cos]]);
+        let mut drifted = rotation;
+        drifted[[0, 1]] += 1e-4;
+        drifted[[2, 0]] -= 2e-4;
+        drifted[[1, 1]] += 3e-4;
+        assert!((drifted.gram()[[0, 0]] - 1.0).abs() > 1e-5);
+
+        let restored = drifted.orthonormalized();
+        assert_close(restored.gram(), SquareMatrix::mul_identity(), 1e-12);
+        assert_close(restored, rotation, 1e-3);
+    }
+}
@@ -1,15 +1,33 @@
 //! Bivectors and Roters are not abstracted over their dimension because I don't know how
 //! I will probably fix this in the future!
+mod banded;
 mod bivec;
 mod dynamic;
+mod einsum;
+mod fixed;
 mod mat;
+mod modular;
+mod nonlinear;
+mod operator;
+mod reduce;
 mod rotor;
 mod scalar;
+mod sparse;
+mod stats;
 mod vec;
 
+pub use banded::BandedMatrix;
 pub use bivec::*;
 pub use dynamic::*;
+pub use einsum::einsum;
+pub use fixed::Fixed;
 pub use mat::*;
+pub use modular::Mod;
+pub use nonlinear::{newton_solve, numerical_jacobian};
+pub use operator::*;
+pub use reduce::{Monoid, Reduce};
 pub use rotor::*;
 pub use scalar::Scalar;
+pub use sparse::SparseVector;
+pub use stats::*;
 pub use vec::*;
@@ -0,0 +1,265 @@
+use crate::algebra::linear::{DeterminantAlgorithm, SquareMatrix, Vector};
+use fructose::operators::{Additive, Multiplicative};
+use fructose::properties::general::{Identity, Set};
+use std::fmt::{Display, Formatter};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// integer modulo `P`. for a prime `P` this is the finite field GF(P) and division multiplies
+/// with the modular inverse, dividing by a non-invertible element panics
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Mod<const P: u64> {
+    val: u64,
+}
+
+impl<const P: u64> Mod<P> {
+    #[inline]
+    pub const fn new(val: u64) -> Self {
+        Self { val: val % P }
+    }
+
+    /// maps negative integers to their representative in `0..P`
+    #[inline]
+    pub const fn from_signed(val: i64) -> Self {
+        Self {
+            val: val.rem_euclid(P as i64) as u64,
+        }
+    }
+
+    #[inline]
+    pub const fn value(&self) -> u64 {
+        self.val
+    }
+
+    /// multiplicative inverse via the extended euclidean algorithm,
+    /// `None` if `self` and `P` aren't coprime
+    pub fn inverse(&self) -> Option<Self> {
+        let (mut r0, mut r1) = (P as i128, self.val as i128);
+        let (mut t0, mut t1) = (0i128, 1i128);
+        while r1 != 0 {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (t0, t1) = (t1, t0 - q * t1);
+        }
+        if r0 != 1 {
+            return None;
+        }
+        Some(Self {
+            val: t0.rem_euclid(P as i128) as u64,
+        })
+    }
+}
+
+impl<const P: u64> Display for Mod<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (mod {})", self.val, P)
+    }
+}
+
+impl<const P: u64> Add for Mod<P> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            val: ((self.val as u128 + rhs.val as u128) % P as u128) as u64,
+        }
+    }
+}
+
+impl<const P: u64> Sub for Mod<P> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> Mul for Mod<P> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            val: ((self.val as u128 * rhs.val as u128) % P as u128) as u64,
+        }
+    }
+}
+
+impl<const P: u64> Div for Mod<P> {
+    type Output = Self;
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs
+            .inverse()
+            .unwrap_or_else(|| panic!("{} is not invertible modulo {}", rhs.val, P))
+    }
+}
+
+/// every division by an invertible element is exact, so the remainder is always zero
+impl<const P: u64> Rem for Mod<P> {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: Self) -> Self::Output {
+        self - (self / rhs) * rhs
+    }
+}
+
+impl<const P: u64> Neg for Mod<P> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            val: (P - self.val) % P,
+        }
+    }
+}
+
+macro_rules! impl_assign {
+    ($($trait:ident $fn:ident $op:tt)*) => {
+        $(
+            impl<const P: u64> $trait for Mod<P> {
+                #[inline]
+                fn $fn(&mut self, rhs: Self) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+impl_assign!(
+    AddAssign add_assign +
+    SubAssign sub_assign -
+    MulAssign mul_assign *
+    DivAssign div_assign /
+    RemAssign rem_assign %
+);
+
+impl<const P: u64> Set<Additive> for Mod<P> {
+    fn operate(&self, rhs: Self) -> Self {
+        *self + rhs
+    }
+}
+
+impl<const P: u64> Identity<Additive> for Mod<P> {
+    fn identity() -> Self {
+        Self::new(0)
+    }
+
+    fn is_identity(&self) -> bool {
+        self.val == 0
+    }
+}
+
+impl<const P: u64> Set<Multiplicative> for Mod<P> {
+    fn operate(&self, rhs: Self) -> Self {
+        *self * rhs
+    }
+}
+
+impl<const P: u64> Identity<Multiplicative> for Mod<P> {
+    fn identity() -> Self {
+        Self::new(1)
+    }
+
+    fn is_identity(&self) -> bool {
+        *self == Self::new(1)
+    }
+}
+
+/// the bareiss algorithm divides by earlier pivots, for a composite `P` that panics if one of
+/// them isn't invertible. `determinant_laplace` doesn't divide at all
+impl<const P: u64> DeterminantAlgorithm for Mod<P> {
+    #[inline]
+    fn determinant<const M: usize>(mat: &SquareMatrix<Self, { M }>) -> Self {
+        mat.determinant_bareiss()
+    }
+}
+
+impl<const P: u64, const N: usize> SquareMatrix<Mod<P>, { N }> {
+    /// solves `self * x = b` with gaussian elimination, pivots are inverted modularly.
+    /// returns `None` if no invertible pivot is left in a column (e.g. `self` is singular)
+    pub fn solve_mod(&self, b: Vector<Mod<P>, { N }>) -> Option<Vector<Mod<P>, { N }>> {
+        let mut mat = *self;
+        let mut x = b;
+
+        for k in 0..N {
+            let (pivot, inverse) =
+                (k..N).find_map(|i| mat[[i, k]].inverse().map(|inverse| (i, inverse)))?;
+            if pivot != k {
+                for n in 0..N {
+                    mat.data[n].swap(pivot, k);
+                }
+                x.data[0].swap(pivot, k);
+            }
+
+            for n in k..N {
+                mat[[k, n]] *= inverse;
+            }
+            x[k] *= inverse;
+
+            for i in 0..N {
+                if i == k {
+                    continue;
+                }
+                let factor = mat[[i, k]];
+                for n in k..N {
+                    let sub = factor * mat[[k, n]];
+                    mat[[i, n]] -= sub;
+                }
+                let sub = factor * x[k];
+                x[i] -= sub;
+            }
+        }
+
+        Some(x)
+    }
+}
+
+#[cfg(test)]
+mod modular_tests {
+    use crate::algebra::linear::{Mod, SquareMatrix, Vector};
+
+    type F7 = Mod<7>;
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(F7::new(5) + F7::new(4), F7::new(2));
+        assert_eq!(F7::new(2) - F7::new(5), F7::new(4));
+        assert_eq!(F7::new(3) * F7::new(5), F7::new(1));
+        assert_eq!(F7::new(3).inverse(), Some(F7::new(5)));
+        assert_eq!(F7::new(1) / F7::new(3), F7::new(5));
+        assert_eq!(F7::from_signed(-1), F7::new(6));
+        assert_eq!(Mod::<6>::new(2).inverse(), None);
+    }
+
+    #[test]
+    fn determinant() {
+        // det = 2 * (1 - 0) - 1 * (0 - 2) + 3 * (0 - 1) = 1
+        let mat = SquareMatrix::new([[2, 0, 1], [1, 1, 0], [3, 2, 1]]).map_into(F7::new);
+        assert_eq!(mat.determinant(), F7::new(1));
+        assert_eq!(mat.determinant_bareiss(), F7::new(1));
+        assert_eq!(mat.determinant_laplace(), F7::new(1));
+    }
+
+    #[test]
+    fn solve() {
+        // 2x + y = 3, x + 3y = 5 over GF(7): x = 4 / 5 = 5, y = 3 - 2x = 0
+        let mat = SquareMatrix::new([[2, 1], [1, 3]]).map_into(F7::new);
+        let b = Vector::from([3, 5]).map_into(F7::new);
+        let x = mat.solve_mod(b).unwrap();
+        assert_eq!(x, Vector::from([5, 0]).map_into(F7::new));
+        assert_eq!(mat * x, b);
+
+        let singular = SquareMatrix::new([[1, 2], [3, 6]]).map_into(F7::new);
+        assert_eq!(singular.determinant_bareiss(), F7::new(0));
+        assert_eq!(singular.solve_mod(b), None);
+
+        // GF(2)
+        let mat = SquareMatrix::new([[1, 1, 0], [0, 1, 1], [1, 0, 0]]).map_into(Mod::<2>::new);
+        let b = Vector::from([1, 0, 1]).map_into(Mod::<2>::new);
+        let x = mat.solve_mod(b).unwrap();
+        assert_eq!(mat * x, b);
+    }
+}
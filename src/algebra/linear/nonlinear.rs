@@ -0,0 +1,110 @@
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::{Matrix, Vector};
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::float::Float;
+use fructose::properties::helpers::identity::One;
+use fructose::specific::complex::Real;
+
+/// jacobian of `f` at `x` approximated with central differences `(f(x + h) - f(x - h)) / 2h`,
+/// column `n` holds the partial derivatives with respect to `x[n]`. the truncation error is
+/// `O(h²)`, too small a step amplifies rounding errors instead
+pub fn numerical_jacobian<
+    T: Scalar + ClosedOps + One,
+    F: Fn(Vector<T, { N }>) -> Vector<T, { M }>,
+    const M: usize,
+    const N: usize,
+>(
+    f: F,
+    x: Vector<T, { N }>,
+    h: T,
+) -> Matrix<T, { M }, { N }> {
+    let two_h = (T::one() + T::one()) * h;
+    let mut jacobian = Matrix::default();
+    for n in 0..N {
+        let (mut forward, mut backward) = (x, x);
+        forward[n] += h;
+        backward[n] -= h;
+        let (forward, backward) = (f(forward), f(backward));
+        for m in 0..M {
+            jacobian[[m, n]] = (forward[m] - backward[m]) / two_h;
+        }
+    }
+    jacobian
+}
+
+/// root of `f` via newton-raphson iteration `x -= J⁻¹ f(x)` starting at `x0`, the jacobian is
+/// approximated with [`numerical_jacobian`]. converged once every component of `f(x)` is
+/// within `tolerance`, returns `None` if that doesn't happen within `max_iterations` steps
+/// or the jacobian becomes singular
+pub fn newton_solve<
+    T: Scalar + ClosedOps + Real + Float,
+    F: Fn(Vector<T, { N }>) -> Vector<T, { N }>,
+    const N: usize,
+>(
+    f: F,
+    x0: Vector<T, { N }>,
+    max_iterations: usize,
+    tolerance: T,
+) -> Option<Vector<T, { N }>> {
+    let mut x = x0;
+    for _ in 0..=max_iterations {
+        let fx = f(x);
+        if (0..N).all(|n| fx[n].abs() <= tolerance) {
+            return Some(x);
+        }
+
+        // balances truncation and rounding error of the central differences
+        let mut scale = T::one();
+        for n in 0..N {
+            if x[n].abs() > scale {
+                scale = x[n].abs();
+            }
+        }
+        let jacobian = numerical_jacobian(&f, x, T::EPSILON.cbrt() * scale);
+        x -= jacobian.solve(fx)?;
+    }
+    None
+}
+
+#[cfg(test)]
+mod nonlinear_tests {
+    use crate::algebra::linear::{newton_solve, numerical_jacobian, Matrix, Vector};
+
+    #[test]
+    fn jacobian() {
+        // f(x, y) = (x² y, 5x + sin y, x y³)
+        let f = |v: Vector<f64, 2>| {
+            Vector::from([
+                v[0] * v[0] * v[1],
+                5.0 * v[0] + v[1].sin(),
+                v[0] * v[1].powi(3),
+            ])
+        };
+        let (x, y) = (1.5, -0.5);
+        let jacobian = numerical_jacobian(f, Vector::from([x, y]), 1e-5);
+        let expected = Matrix::<f64, 3, 2>::new([
+            [2.0 * x * y, 5.0, y.powi(3)],
+            [x * x, y.cos(), 3.0 * x * y * y],
+        ]);
+        for m in 0..3 {
+            for n in 0..2 {
+                assert!((jacobian[[m, n]] - expected[[m, n]]).abs() < 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn newton() {
+        // intersection of the circle x² + y² = 4 with the parabola y = x² - 2
+        let f = |v: Vector<f64, 2>| {
+            Vector::from([v[0] * v[0] + v[1] * v[1] - 4.0, v[0] * v[0] - 2.0 - v[1]])
+        };
+        let root = newton_solve(f, Vector::from([1.0, 0.5]), 50, 1e-12).unwrap();
+        assert!((root[0] - 3.0f64.sqrt()).abs() < 1e-10);
+        assert!((root[1] - 1.0).abs() < 1e-10);
+
+        // x² + 1 has no real root
+        let f = |v: Vector<f64, 1>| Vector::from([v[0] * v[0] + 1.0]);
+        assert_eq!(newton_solve(f, Vector::from([0.5]), 50, 1e-12), None);
+    }
+}
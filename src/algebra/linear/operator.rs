@@ -0,0 +1,141 @@
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::{DMatrix, DVector, Matrix};
+use fructose::algebra::lattice::Lattice;
+use fructose::operators::{ClosedAdd, ClosedMul, ClosedOps};
+use fructose::properties::helpers::identity::Zero;
+
+/// a linear map that does not have to be stored as a matrix (e.g. a convolution)
+pub trait LinearOperator<T> {
+    fn apply(&self, x: &DVector<T>) -> DVector<T>;
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul> LinearOperator<T> for DMatrix<T> {
+    fn apply(&self, x: &DVector<T>) -> DVector<T> {
+        assert_eq!(
+            self.size.1, x.len,
+            "matrix of size {:?} can't be applied to a vector of length {}",
+            self.size, x.len
+        );
+        let mut y = DVector::default_with_size(self.size.0);
+        for (col, e) in self.data.iter().zip(x.data.iter()) {
+            for (row, y) in col.iter().zip(y.data.iter_mut()) {
+                *y += *row * *e;
+            }
+        }
+        y
+    }
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul, const M: usize, const N: usize> LinearOperator<T>
+    for Matrix<T, { M }, { N }>
+{
+    fn apply(&self, x: &DVector<T>) -> DVector<T> {
+        assert_eq!(
+            N,
+            x.len,
+            "matrix of size {:?} can't be applied to a vector of length {}",
+            (M, N),
+            x.len
+        );
+        let mut y = DVector::default_with_size(M);
+        for (col, e) in self.data.iter().zip(x.data.iter()) {
+            for (row, y) in col.iter().zip(y.data.iter_mut()) {
+                *y += *row * *e;
+            }
+        }
+        y
+    }
+}
+
+/// solves `op * x = b` for a symmetric positive definite operator with the conjugate gradient
+/// method, starting at `x = 0`. stops once `|b - op * x| <= tolerance * |b|`,
+/// returns `None` if that didn't happen within `max_iterations`
+pub fn conjugate_gradient<T, O>(
+    op: &O,
+    b: &DVector<T>,
+    tolerance: T,
+    max_iterations: usize,
+) -> Option<DVector<T>>
+where
+    T: Scalar + ClosedOps + Lattice + Zero,
+    O: LinearOperator<T> + ?Sized,
+{
+    let mut x = DVector::new(vec![T::zero(); b.len]);
+    let mut residual = b.clone();
+    let mut direction = b.clone();
+    let mut residual_norm = residual.dot(&residual);
+    let threshold = tolerance * tolerance * b.dot(b);
+
+    for _ in 0..=max_iterations {
+        if residual_norm <= threshold {
+            return Some(x);
+        }
+
+        let applied = op.apply(&direction);
+        let alpha = residual_norm / direction.dot(&applied);
+        for i in 0..b.len {
+            x.data[i] += alpha * direction.data[i];
+            residual.data[i] -= alpha * applied.data[i];
+        }
+
+        let next_norm = residual.dot(&residual);
+        let beta = next_norm / residual_norm;
+        for i in 0..b.len {
+            direction.data[i] = residual.data[i] + beta * direction.data[i];
+        }
+        residual_norm = next_norm;
+    }
+    None
+}
+
+#[cfg(test)]
+mod operator_tests {
+    use crate::algebra::linear::{
+        conjugate_gradient, DMatrix, DVector, LinearOperator, SquareMatrix, Vector,
+    };
+
+    struct Diagonal(Vec<f64>);
+
+    impl LinearOperator<f64> for Diagonal {
+        fn apply(&self, x: &DVector<f64>) -> DVector<f64> {
+            DVector::new(
+                self.0
+                    .iter()
+                    .zip(x.data.iter())
+                    .map(|(d, x)| d * x)
+                    .collect(),
+            )
+        }
+    }
+
+    #[test]
+    fn conjugate_gradient_matrix_free() {
+        let op = Diagonal(vec![1.0, 2.0, 4.0, 8.0, 16.0]);
+        let b = DVector::new(vec![1.0, -2.0, 2.0, 4.0, 32.0]);
+        let x = conjugate_gradient(&op, &b, 1e-12, 10).unwrap();
+        let expected = [1.0, -1.0, 0.5, 0.5, 2.0];
+        for (x, expected) in x.data.iter().zip(expected.iter()) {
+            assert!((x - expected).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn conjugate_gradient_matrix() {
+        let mat = DMatrix::<f64>::from("4 1 0;1 3 1;0 1 2");
+        let b = DVector::new(vec![1.0, 2.0, 3.0]);
+        let x = conjugate_gradient(&mat, &b, 1e-12, 10).unwrap();
+        let applied = mat.apply(&x);
+        for i in 0..3 {
+            assert!((applied.data[i] - b.data[i]).abs() < 1e-10);
+        }
+        assert!(conjugate_gradient(&mat, &b, 1e-12, 0).is_none());
+    }
+
+    #[test]
+    fn apply_static_matrix() {
+        let mat = SquareMatrix::<f64, 3>::from(((4.0, 1.0, 0.0), (1.0, 3.0, 1.0), (0.0, 1.0, 2.0)));
+        let x = Vector::from([1.0, -1.0, 2.0]);
+        let applied = mat.apply(&DVector::new(x.as_slice().to_vec()));
+        assert_eq!(applied.data, (mat * x).as_slice().to_vec());
+    }
+}
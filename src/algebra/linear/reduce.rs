@@ -0,0 +1,130 @@
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::{DMatrix, DVector, Matrix};
+use fructose::algebra::lattice::Lattice;
+use fructose::operators::{ClosedAdd, ClosedMul};
+use fructose::properties::helpers::bound::Bounded;
+use fructose::properties::helpers::identity::{One, Zero};
+
+/// associative operation with an identity element used to fold all elements of a collection
+#[derive(Debug, Copy, Clone)]
+pub enum Monoid<T> {
+    Sum,
+    Product,
+    /// the identity is the largest value of `T`
+    Min,
+    /// the identity is the smallest value of `T`
+    Max,
+    /// `operation` has to be associative with `identity` as its identity element
+    Custom {
+        identity: T,
+        operation: fn(T, T) -> T,
+    },
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul + Zero + One + Lattice + Bounded> Monoid<T> {
+    #[inline]
+    pub fn identity(&self) -> T {
+        match self {
+            Monoid::Sum => T::zero(),
+            Monoid::Product => T::one(),
+            Monoid::Min => T::MAX,
+            Monoid::Max => T::MIN,
+            Monoid::Custom { identity, .. } => *identity,
+        }
+    }
+
+    #[inline]
+    pub fn combine(&self, lhs: T, rhs: T) -> T {
+        match self {
+            Monoid::Sum => lhs + rhs,
+            Monoid::Product => lhs * rhs,
+            Monoid::Min => lhs.infimum(&rhs),
+            Monoid::Max => lhs.supremum(&rhs),
+            Monoid::Custom { operation, .. } => operation(lhs, rhs),
+        }
+    }
+}
+
+/// folds all elements of a collection with a [`Monoid`],
+/// an empty collection reduces to the identity of the monoid
+pub trait Reduce<T> {
+    fn reduce(&self, monoid: Monoid<T>) -> T;
+}
+
+impl<
+        T: Scalar + ClosedAdd + ClosedMul + Zero + One + Lattice + Bounded,
+        const M: usize,
+        const N: usize,
+    > Reduce<T> for Matrix<T, { M }, { N }>
+{
+    fn reduce(&self, monoid: Monoid<T>) -> T {
+        self.data
+            .iter()
+            .flatten()
+            .fold(monoid.identity(), |acc, e| monoid.combine(acc, *e))
+    }
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul + Zero + One + Lattice + Bounded> Reduce<T> for DVector<T> {
+    fn reduce(&self, monoid: Monoid<T>) -> T {
+        self.data
+            .iter()
+            .fold(monoid.identity(), |acc, e| monoid.combine(acc, *e))
+    }
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul + Zero + One + Lattice + Bounded> Reduce<T> for DMatrix<T> {
+    fn reduce(&self, monoid: Monoid<T>) -> T {
+        self.data
+            .iter()
+            .flatten()
+            .fold(monoid.identity(), |acc, e| monoid.combine(acc, *e))
+    }
+}
+
+#[cfg(test)]
+mod reduce_tests {
+    use crate::algebra::linear::{DMatrix, DVector, Matrix, Monoid, Reduce, Vector};
+
+    #[test]
+    fn matrix() {
+        let mat = Matrix::<i64, 2, 3>::new([[4, -1], [2, 7], [-3, 5]]);
+        let elements = mat.as_slice();
+        assert_eq!(mat.reduce(Monoid::Sum), elements.iter().sum::<i64>());
+        assert_eq!(
+            mat.reduce(Monoid::Product),
+            elements.iter().product::<i64>()
+        );
+        assert_eq!(mat.reduce(Monoid::Min), *elements.iter().min().unwrap());
+        assert_eq!(mat.reduce(Monoid::Max), *elements.iter().max().unwrap());
+
+        let bitwise_or = Monoid::Custom {
+            identity: 0,
+            operation: |lhs, rhs| lhs | rhs,
+        };
+        let vec = Vector::from([1, 4, 8]);
+        assert_eq!(vec.reduce(bitwise_or), 13);
+    }
+
+    #[test]
+    fn dynamic() {
+        let vec = DVector::new(vec![0.5, -2.0, 3.0, 1.5]);
+        assert_eq!(vec.reduce(Monoid::Sum), vec.data.iter().sum::<f64>());
+        assert_eq!(
+            vec.reduce(Monoid::Product),
+            vec.data.iter().product::<f64>()
+        );
+        assert_eq!(vec.reduce(Monoid::Min), -2.0);
+        assert_eq!(vec.reduce(Monoid::Max), 3.0);
+
+        let mat = DMatrix::<i32>::from("1 2;3 4;5 6");
+        assert_eq!(mat.reduce(Monoid::Sum), 21);
+        assert_eq!(mat.reduce(Monoid::Product), 720);
+
+        let empty = DVector::<i32>::new(Vec::new());
+        assert_eq!(empty.reduce(Monoid::Sum), 0);
+        assert_eq!(empty.reduce(Monoid::Product), 1);
+        assert_eq!(empty.reduce(Monoid::Min), i32::MAX);
+        assert_eq!(empty.reduce(Monoid::Max), i32::MIN);
+    }
+}
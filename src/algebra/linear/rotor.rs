@@ -1,4 +1,4 @@
-use crate::algebra::linear::bivec::Bivector2;
+use crate::algebra::linear::bivec::{Bivector2, Bivector3};
 use crate::algebra::linear::mat::Matrix;
 use crate::algebra::linear::scalar::Scalar;
 use crate::algebra::linear::vec::Vector;
@@ -8,7 +8,7 @@ use fructose::operators::{Additive, ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg,
 use fructose::properties::general::{Associative, Identity, Set, Total};
 use fructose::properties::helpers::identity::{One, Two, Zero};
 use fructose::specific::complex::Real;
-use std::ops::{Add, AddAssign};
+use std::ops::{Add, AddAssign, Mul};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Rotor2<S> {
@@ -56,6 +56,13 @@ impl<S: Scalar + One + Zero + ClosedOps + ClosedNeg> Rotor2<S> {
     }
 }
 
+impl<S: Scalar + One + Zero + ClosedOps + ClosedNeg> Default for Rotor2<S> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
 impl<S: Scalar + ClosedOps + MulAdd<Output = S>> Rotor2<S> {
     #[inline]
     pub fn rotate_by(&mut self, other: Self) {
@@ -169,3 +176,358 @@ impl<T: Scalar + Identity<Additive> + ClosedAdd> Identity<Additive> for Rotor2<T
         *self == Self::identity()
     }
 }
+
+/// 3D rotor `scalar + bivector`, a rotation by `angle` in the plane `B` is
+/// `cos(angle / 2) - sin(angle / 2) * B`
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rotor3<S> {
+    pub scalar: S,
+    pub bivector: Bivector3<S>,
+}
+
+impl<S> Rotor3<S> {
+    #[inline]
+    pub const fn new(scalar: S, bivector: Bivector3<S>) -> Self {
+        Self { scalar, bivector }
+    }
+}
+
+impl<S: Scalar + Zero + One> Rotor3<S> {
+    /// the rotation that does nothing, scalar `1` and a zero bivector
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(S::one(), Bivector3::new(S::zero(), S::zero(), S::zero()))
+    }
+}
+
+impl<S: Scalar + Zero + One> Default for Rotor3<S> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<S: Scalar + ClosedOps + One> Rotor3<S> {
+    /// rotates `vec` with the sandwich product `R v R̃`
+    pub fn rotate(&self, vec: Vector<S, 3>) -> Vector<S, 3> {
+        // the quaternion with the vector part `-a` for the dual `a = (yz, zx, xy)` describes
+        // the same rotation, `v + 2 s (-a × v) + 2 (-a) × (-a × v)`
+        let b = self.bivector;
+        let dual = Vector::from([b.yz, b.zx, b.xy]);
+        let two = S::one() + S::one();
+        let t = dual.cross(&vec);
+        let mut u = t;
+        u *= self.scalar * two;
+        let mut w = dual.cross(&t);
+        w *= two;
+        vec - u + w
+    }
+
+    /// the reverse `R̃ = scalar - bivector`, the inverse rotation of a unit rotor
+    #[inline]
+    pub fn reversed(&self) -> Self {
+        Self::new(self.scalar, Bivector3::default() - self.bivector)
+    }
+}
+
+/// geometric product, composes the rotations so that `a * b` applies `b` first.
+/// with the bivector duals `a = (yz, zx, xy)` this is `s1 s2 - a1 · a2` for the scalar and
+/// `s1 a2 + s2 a1 - a1 × a2` for the bivector part, like a quaternion product
+impl<S: Scalar + ClosedOps> Mul for Rotor3<S> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.bivector, rhs.bivector);
+        let scalar = self.scalar * rhs.scalar - (a.yz * b.yz + a.zx * b.zx + a.xy * b.xy);
+        let yz = self.scalar * b.yz + rhs.scalar * a.yz - (a.zx * b.xy - a.xy * b.zx);
+        let zx = self.scalar * b.zx + rhs.scalar * a.zx - (a.xy * b.yz - a.yz * b.xy);
+        let xy = self.scalar * b.xy + rhs.scalar * a.xy - (a.yz * b.zx - a.zx * b.yz);
+        Self::new(scalar, Bivector3::new(xy, yz, zx))
+    }
+}
+
+/// geometric product `a b = a · b + a ∧ b` of two vectors, the scalar part is the dot product
+/// and the bivector part the wedge product (so `e1 e2 = e12`). with the sign convention of
+/// `Rotor3`, the reversed product `b a` of unit vectors rotates by twice the angle from `a`
+/// to `b`
+pub fn geometric_product<S: Scalar + ClosedOps>(a: &Vector<S, 3>, b: &Vector<S, 3>) -> Rotor3<S> {
+    Rotor3::new(a.dot(*b), a.wedge(b))
+}
+
+impl<S: Scalar + ClosedOps + Real + TrigOps> Rotor3<S> {
+    /// rotation by `angle` in the plane of `plane`, `cos(angle / 2) - sin(angle / 2) * B̂`
+    /// with the normalized plane `B̂`. a zero plane gives the identity
+    pub fn from_angle_plane(angle: S, plane: Bivector3<S>) -> Self {
+        let magnitude = plane.magnitude();
+        if magnitude.is_zero() {
+            return Self::identity();
+        }
+        let half_angle = angle / (S::one() + S::one());
+        let factor = -half_angle.sin() / magnitude;
+        Self::new(half_angle.cos(), plane * factor)
+    }
+
+    #[inline]
+    pub fn magnitude(&self) -> S {
+        let b = self.bivector;
+        (self.scalar * self.scalar + b.xy * b.xy + b.yz * b.yz + b.zx * b.zx).sqrt()
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let magnitude = self.magnitude();
+        let b = self.bivector;
+        Self::new(
+            self.scalar / magnitude,
+            Bivector3::new(b.xy / magnitude, b.yz / magnitude, b.zx / magnitude),
+        )
+    }
+
+    /// exponential map `exp(B) = cos(|B|) + sin(|B|) * B̂`, so `from_angle_plane(angle, B̂)` is
+    /// `exp(-angle / 2 * B̂)`
+    pub fn exp(bivector: Bivector3<S>) -> Self {
+        let magnitude = bivector.magnitude();
+        if magnitude.is_zero() {
+            return Self::identity();
+        }
+        Self::new(magnitude.cos(), bivector * (magnitude.sin() / magnitude))
+    }
+
+    /// inverse of `exp` for unit rotors, the magnitude of the logarithm is at most `π`
+    pub fn log(&self) -> Bivector3<S> {
+        let magnitude = self.bivector.magnitude();
+        if magnitude.is_zero() {
+            return Bivector3::default();
+        }
+        self.bivector * (magnitude.atan2(self.scalar) / magnitude)
+    }
+
+    /// weighted average of unit rotors as the weighted mean of the logarithms relative to the
+    /// first rotor, `R0 exp(Σ w_i log(R̃0 R_i) / Σ w_i)`. `R` and `-R` are the same rotation,
+    /// so every relative rotor is taken in the hemisphere of the identity.
+    /// panics if there are no rotors or the number of weights doesn't match
+    pub fn average(rotors: &[Self], weights: &[S]) -> Self {
+        assert!(!rotors.is_empty(), "can't average zero rotors");
+        assert_eq!(
+            rotors.len(),
+            weights.len(),
+            "every rotor needs exactly one weight"
+        );
+        let reference = rotors[0];
+        let inverse = reference.reversed();
+        let mut sum = Bivector3::default();
+        let mut total = S::zero();
+        for (rotor, weight) in rotors.iter().zip(weights.iter()) {
+            let mut relative = inverse * *rotor;
+            if relative.scalar < S::zero() {
+                relative = Self::new(
+                    S::zero() - relative.scalar,
+                    Bivector3::default() - relative.bivector,
+                );
+            }
+            sum += relative.log() * *weight;
+            total += *weight;
+        }
+        reference * Self::exp(sum / total)
+    }
+
+    /// advances the orientation by the angular velocity `angular_velocity` (rotating in its
+    /// plane by its magnitude per unit of time) over `dt`. with the sign convention above this
+    /// is `exp(-angular_velocity * dt / 2) * self`, the result is renormalized
+    pub fn integrate(&self, angular_velocity: Bivector3<S>, dt: S) -> Self {
+        let w = angular_velocity;
+        let speed = w.magnitude();
+        if speed.is_zero() {
+            return *self;
+        }
+
+        let half_angle = speed * dt / (S::one() + S::one());
+        let (sin, cos) = (half_angle.sin(), half_angle.cos());
+        let factor = -sin / speed;
+        let step = Self::new(cos, w * factor);
+        (step * *self).normalized()
+    }
+}
+
+#[cfg(test)]
+mod rotor_tests {
+    use crate::algebra::linear::{geometric_product, Bivector3, Rotor3, Vector};
+
+    #[test]
+    fn integrate() {
+        // 0.8 rad/s in the xy plane combined with 0.3 rad/s in the yz plane
+        let omega = Bivector3::<f64>::new(0.8, 0.3, 0.0);
+        let mut rotor = Rotor3::new(1.0, Bivector3::default());
+        let dt = 0.001;
+        for _ in 0..2000 {
+            rotor = rotor.integrate(omega, dt);
+        }
+
+        let speed = (0.8f64 * 0.8 + 0.3 * 0.3).sqrt();
+        let half_angle = speed * 2.0 / 2.0;
+        let factor = -half_angle.sin() / speed;
+        let expected = Rotor3::new(
+            half_angle.cos(),
+            Bivector3::new(0.8 * factor, 0.3 * factor, 0.0),
+        );
+        assert!((rotor.magnitude() - 1.0).abs() < 1e-12);
+        assert!((rotor.scalar - expected.scalar).abs() < 1e-9);
+        assert!((rotor.bivector.xy - expected.bivector.xy).abs() < 1e-9);
+        assert!((rotor.bivector.yz - expected.bivector.yz).abs() < 1e-9);
+        assert!(rotor.bivector.zx.abs() < 1e-9);
+    }
+
+    #[test]
+    fn integrate_composes() {
+        // a quarter turn around z followed by a quarter turn around x
+        let quarter = std::f64::consts::FRAC_PI_2;
+        let identity = Rotor3::<f64>::new(1.0, Bivector3::default());
+        let rotor = identity
+            .integrate(Bivector3::new(quarter, 0.0, 0.0), 1.0)
+            .integrate(Bivector3::new(0.0, quarter, 0.0), 1.0);
+        // exp(-yz π/4) * exp(-xy π/4) = (1 - e23)(1 - e12) / 2 = (1 - e12 - e23 + e31) / 2
+        assert!((rotor.scalar - 0.5).abs() < 1e-12);
+        assert!((rotor.bivector.xy + 0.5).abs() < 1e-12);
+        assert!((rotor.bivector.yz + 0.5).abs() < 1e-12);
+        assert!((rotor.bivector.zx - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn geometric_product_of_vectors() {
+        let (sin, cos) = 0.4f64.sin_cos();
+        let a = Vector::<f64, 3>::from([cos, 0.0, sin]);
+        let square = geometric_product(&a, &a);
+        assert!((square.scalar - 1.0).abs() < 1e-12);
+        assert_eq!(square.bivector, Bivector3::default());
+
+        let x = Vector::<f64, 3>::unit(0);
+        let y = Vector::unit(1);
+        assert_eq!(
+            geometric_product(&x, &y),
+            Rotor3::new(0.0, Bivector3::new(1.0, 0.0, 0.0))
+        );
+        assert_eq!(
+            geometric_product(&y, &x),
+            Rotor3::new(0.0, Bivector3::new(-1.0, 0.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn from_angle_plane() {
+        let quarter = std::f64::consts::FRAC_PI_2;
+        let rotor = Rotor3::from_angle_plane(quarter, Bivector3::new(2.0, 0.0, 0.0));
+        let half = std::f64::consts::FRAC_1_SQRT_2;
+        assert!((rotor.scalar - half).abs() < 1e-12);
+        assert!((rotor.bivector.xy + half).abs() < 1e-12);
+
+        // x rotates towards y in the xy-plane
+        let rotated = rotor.rotate(Vector::from([1.0, 0.0, 0.0]));
+        assert!((rotated - Vector::from([0.0, 1.0, 0.0])).norm_linf() < 1e-12);
+        let rotated = rotor.rotate(Vector::from([0.0, 1.0, 5.0]));
+        assert!((rotated - Vector::from([-1.0, 0.0, 5.0])).norm_linf() < 1e-12);
+
+        // y rotates towards z in the yz-plane
+        let rotor = Rotor3::from_angle_plane(quarter, Bivector3::new(0.0, 1.0, 0.0));
+        let rotated = rotor.rotate(Vector::from([0.0, 1.0, 0.0]));
+        assert!((rotated - Vector::from([0.0, 0.0, 1.0])).norm_linf() < 1e-12);
+
+        let identity = Rotor3::from_angle_plane(1.0, Bivector3::default());
+        assert_eq!(identity, Rotor3::new(1.0, Bivector3::default()));
+    }
+
+    #[test]
+    fn compose() {
+        // two 45 degree rotations in the xy-plane make one 90 degree rotation
+        let plane = Bivector3::new(1.0, 0.0, 0.0);
+        let half = Rotor3::from_angle_plane(std::f64::consts::FRAC_PI_4, plane);
+        let composed = half * half;
+        let expected = Rotor3::from_angle_plane(std::f64::consts::FRAC_PI_2, plane);
+        assert!((composed.scalar - expected.scalar).abs() < 1e-12);
+        assert!((composed.bivector.xy - expected.bivector.xy).abs() < 1e-12);
+
+        // `a * b` applies `b` first
+        let a = Rotor3::from_angle_plane(0.7, Bivector3::new(0.0, 1.0, 0.0));
+        let b = Rotor3::from_angle_plane(1.1, Bivector3::new(0.3, 0.0, 1.0));
+        let vec = Vector::from([1.0, -2.0, 0.5]);
+        let rotated = (a * b).rotate(vec);
+        assert!((rotated - a.rotate(b.rotate(vec))).norm_linf() < 1e-12);
+    }
+
+    #[test]
+    fn identity() {
+        let identity = Rotor3::<f64>::identity();
+        assert_eq!(identity, Rotor3::default());
+        assert_eq!(identity, Rotor3::new(1.0, Bivector3::default()));
+        let vec = Vector::from([1.0, -2.0, 0.5]);
+        assert_eq!(identity.rotate(vec), vec);
+
+        let rotor = Rotor3::from_angle_plane(0.9, Bivector3::new(0.2, -1.0, 0.4));
+        assert_eq!(identity * rotor, rotor);
+        assert_eq!(rotor * identity, rotor);
+    }
+
+    fn assert_close(a: Rotor3<f64>, b: Rotor3<f64>) {
+        assert!((a.scalar - b.scalar).abs() < 1e-12, "{:?} != {:?}", a, b);
+        assert!(
+            (a.bivector - b.bivector).magnitude() < 1e-12,
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn exp_log() {
+        let plane = Bivector3::new(0.2, -1.0, 0.4);
+        let rotor = Rotor3::from_angle_plane(0.9, plane);
+        assert_close(Rotor3::exp(plane.normalized() * -0.45), rotor);
+        assert_close(Rotor3::exp(rotor.log()), rotor);
+        assert!((rotor.log().magnitude() - 0.45).abs() < 1e-12);
+        assert_eq!(Rotor3::<f64>::identity().log(), Bivector3::default());
+        assert_eq!(Rotor3::exp(Bivector3::default()), Rotor3::<f64>::identity());
+    }
+
+    #[test]
+    fn average() {
+        let plane = Bivector3::new(0.2, -1.0, 0.4);
+        let rotor = Rotor3::from_angle_plane(0.9, plane);
+        assert_close(Rotor3::average(&[rotor, rotor], &[0.3, 0.7]), rotor);
+        assert_close(Rotor3::average(&[rotor], &[2.0]), rotor);
+
+        // rotations in the same plane average their angles
+        let a = Rotor3::from_angle_plane(0.2, plane);
+        let b = Rotor3::from_angle_plane(1.0, plane);
+        assert_close(
+            Rotor3::average(&[a, b], &[1.0, 1.0]),
+            Rotor3::from_angle_plane(0.6, plane),
+        );
+        assert_close(
+            Rotor3::average(&[a, b], &[3.0, 1.0]),
+            Rotor3::from_angle_plane(0.4, plane),
+        );
+
+        // the midpoint of two rotations in different planes lies halfway between them
+        let c = Rotor3::from_angle_plane(0.8, Bivector3::new(0.0, 0.0, 1.0));
+        let mean = Rotor3::average(&[a, c], &[1.0, 1.0]);
+        let to_a = (mean.reversed() * a).log().magnitude();
+        let to_c = (mean.reversed() * c).log().magnitude();
+        let between = (a.reversed() * c).log().magnitude();
+        assert!((to_a - to_c).abs() < 1e-12);
+        assert!((to_a + to_c - between).abs() < 1e-12);
+
+        // `-R` is the same rotation as `R`
+        let negated = Rotor3::new(-b.scalar, -b.bivector);
+        assert_close(
+            Rotor3::average(&[a, negated], &[1.0, 1.0]),
+            Rotor3::from_angle_plane(0.6, plane),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "exactly one weight")]
+    fn average_mismatched_weights() {
+        let rotor = Rotor3::<f64>::identity();
+        Rotor3::average(&[rotor, rotor], &[1.0]);
+    }
+}
@@ -0,0 +1,167 @@
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::DVector;
+use fructose::operators::{ClosedAdd, ClosedMul};
+use fructose::properties::helpers::identity::Zero;
+use std::ops::Add;
+
+/// vector of length `len` that only stores its nonzero elements as `(index, value)` pairs
+/// sorted by index
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseVector<T> {
+    entries: Vec<(usize, T)>,
+    len: usize,
+}
+
+impl<T> SparseVector<T> {
+    /// sorts the entries by index, panics if an index is out of bounds or repeated
+    pub fn new(len: usize, entries: Vec<(usize, T)>) -> Self {
+        Self::validated(len, entries).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// like `new` but returns the reason instead of panicking
+    pub(crate) fn validated(len: usize, mut entries: Vec<(usize, T)>) -> Result<Self, String> {
+        entries.sort_by_key(|(index, _)| *index);
+        for pair in entries.windows(2) {
+            if pair[0].0 == pair[1].0 {
+                return Err(format!("index {} is repeated", pair[0].0));
+            }
+        }
+        if let Some((index, _)) = entries.last() {
+            if *index >= len {
+                return Err(format!("index {} out of bounds for length {}", index, len));
+            }
+        }
+        Ok(Self { entries, len })
+    }
+
+    /// the stored `(index, value)` pairs, sorted by index
+    #[inline]
+    pub fn entries(&self) -> &[(usize, T)] {
+        &self.entries
+    }
+
+    /// length of the dense vector
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T: Scalar + Zero> SparseVector<T> {
+    /// keeps the nonzero elements of `vec`
+    pub fn from_dense(vec: &DVector<T>) -> Self {
+        let entries = vec
+            .data
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| !e.is_zero())
+            .map(|(index, e)| (index, *e))
+            .collect();
+        Self {
+            entries,
+            len: vec.len,
+        }
+    }
+}
+
+impl<T: Default + Copy> SparseVector<T> {
+    pub fn to_dense(&self) -> DVector<T> {
+        let mut vec = DVector::default_with_size(self.len);
+        for &(index, e) in self.entries.iter() {
+            vec.data[index] = e;
+        }
+        vec
+    }
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul> SparseVector<T> {
+    /// dot product with a dense vector, only touches the stored elements
+    pub fn dot(&self, other: &DVector<T>) -> T {
+        assert_eq!(self.len, other.len);
+        let mut sum = T::default();
+        for &(index, e) in self.entries.iter() {
+            sum += e * other.data[index];
+        }
+        sum
+    }
+}
+
+impl<T: Scalar + ClosedAdd> Add for SparseVector<T> {
+    type Output = Self;
+
+    /// merges the sorted entries, elements stored in both vectors are summed
+    fn add(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.len, rhs.len);
+        let mut entries = Vec::with_capacity(self.entries.len() + rhs.entries.len());
+        let mut lhs = self.entries.into_iter().peekable();
+        let mut rhs = rhs.entries.into_iter().peekable();
+        loop {
+            let entry = match (lhs.peek(), rhs.peek()) {
+                (Some(&(i, a)), Some(&(j, b))) => {
+                    if i < j {
+                        lhs.next();
+                        (i, a)
+                    } else if j < i {
+                        rhs.next();
+                        (j, b)
+                    } else {
+                        lhs.next();
+                        rhs.next();
+                        (i, a + b)
+                    }
+                }
+                (Some(_), None) => lhs.next().unwrap(),
+                (None, Some(_)) => rhs.next().unwrap(),
+                (None, None) => break,
+            };
+            entries.push(entry);
+        }
+        Self {
+            entries,
+            len: self.len,
+        }
+    }
+}
+
+#[cfg(test)]
+mod sparse_tests {
+    use crate::algebra::linear::{DVector, SparseVector};
+
+    #[test]
+    fn dot() {
+        let dense = DVector::new(vec![0.0, 2.0, 0.0, 0.0, -1.5, 0.0, 3.0]);
+        let sparse = SparseVector::from_dense(&dense);
+        assert_eq!(sparse.entries(), &[(1, 2.0), (4, -1.5), (6, 3.0)]);
+        assert_eq!(sparse.to_dense().data, dense.data);
+
+        let other = DVector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+        let expected: f64 = dense
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(a, b)| a * b)
+            .sum();
+        assert_eq!(sparse.dot(&other), expected);
+    }
+
+    #[test]
+    fn add() {
+        let a = SparseVector::new(6, vec![(4, 1), (0, 2), (2, 3)]);
+        let b = SparseVector::new(6, vec![(2, -1), (5, 4)]);
+        let sum = a.clone() + b.clone();
+        assert_eq!(sum.entries(), &[(0, 2), (2, 2), (4, 1), (5, 4)]);
+        assert_eq!(sum.len(), 6);
+        assert_eq!(sum.to_dense().data, (a.to_dense() + b.to_dense()).data);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn out_of_bounds() {
+        SparseVector::new(3, vec![(0, 1), (3, 2)]);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::{SquareMatrix, Vector};
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::identity::{One, Zero};
+
+/// running mean and (sample) covariance of `Vector<T, N>` samples using Welford's algorithm
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct OnlineStats<T, const N: usize> {
+    len: usize,
+    count: T,
+    mean: Vector<T, { N }>,
+    // sum of the outer products of the deviations
+    m2: SquareMatrix<T, { N }>,
+}
+
+impl<T: Scalar + ClosedOps + Zero + One, const N: usize> OnlineStats<T, { N }> {
+    pub fn new() -> Self {
+        Self {
+            len: 0,
+            count: T::zero(),
+            mean: Vector::default(),
+            m2: SquareMatrix::default(),
+        }
+    }
+
+    pub fn push(&mut self, sample: Vector<T, { N }>) {
+        self.len += 1;
+        self.count += T::one();
+        let delta = sample - self.mean;
+        for i in 0..N {
+            self.mean[i] += delta[i] / self.count;
+        }
+        let delta_new = sample - self.mean;
+        for m in 0..N {
+            for n in 0..N {
+                self.m2[[m, n]] += delta[m] * delta_new[n];
+            }
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn mean(&self) -> Vector<T, { N }> {
+        self.mean
+    }
+
+    /// sample covariance (divided by `count - 1`), zero until at least two samples were pushed
+    pub fn covariance(&self) -> SquareMatrix<T, { N }> {
+        let mut covariance = SquareMatrix::default();
+        if self.len < 2 {
+            return covariance;
+        }
+        let denominator = self.count - T::one();
+        for m in 0..N {
+            for n in 0..N {
+                covariance[[m, n]] = self.m2[[m, n]] / denominator;
+            }
+        }
+        covariance
+    }
+}
+
+impl<T: Scalar + ClosedOps + Zero + One, const N: usize> Default for OnlineStats<T, { N }> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// arithmetic mean of the samples, zero if there are none
+pub fn mean<T: Scalar + ClosedOps + Zero + One, const N: usize>(
+    samples: &[Vector<T, { N }>],
+) -> Vector<T, { N }> {
+    let mut mean = Vector::default();
+    let mut count = T::zero();
+    for sample in samples {
+        mean += *sample;
+        count += T::one();
+    }
+    if !count.is_zero() {
+        mean /= count;
+    }
+    mean
+}
+
+/// sample covariance (divided by `len - 1`) of the samples, zero for less than two samples
+pub fn covariance<T: Scalar + ClosedOps + Zero + One, const N: usize>(
+    samples: &[Vector<T, { N }>],
+) -> SquareMatrix<T, { N }> {
+    let mut covariance = SquareMatrix::default();
+    if samples.len() < 2 {
+        return covariance;
+    }
+
+    let mean = mean(samples);
+    let mut denominator = T::zero();
+    for sample in samples {
+        let delta = *sample - mean;
+        for m in 0..N {
+            for n in 0..N {
+                covariance[[m, n]] += delta[m] * delta[n];
+            }
+        }
+        denominator += T::one();
+    }
+    covariance /= denominator - T::one();
+    covariance
+}
+
+#[cfg(test)]
+mod stats_tests {
+    use crate::algebra::linear::{covariance, mean, OnlineStats, Vector};
+
+    #[test]
+    fn online_matches_batch() {
+        let samples = [
+            Vector::<f64, 3>::from([1.0, 2.0, -0.5]),
+            Vector::from([2.5, -1.0, 0.0]),
+            Vector::from([0.3, 4.0, 1.5]),
+            Vector::from([-2.0, 0.5, 2.0]),
+            Vector::from([1.7, 1.1, -3.0]),
+            Vector::from([0.0, -2.2, 0.4]),
+        ];
+
+        let mut stats = OnlineStats::new();
+        for sample in samples.iter() {
+            stats.push(*sample);
+        }
+
+        let batch_mean = mean(&samples);
+        let batch_covariance = covariance(&samples);
+        for m in 0..3 {
+            assert!((stats.mean()[m] - batch_mean[m]).abs() < 1e-12);
+            for n in 0..3 {
+                assert!((stats.covariance()[[m, n]] - batch_covariance[[m, n]]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn single_sample() {
+        let mut stats = OnlineStats::new();
+        stats.push(Vector::from([1.0, 2.0]));
+        assert_eq!(stats.mean(), Vector::from([1.0, 2.0]));
+        assert_eq!(stats.covariance(), Default::default());
+    }
+}
@@ -1,7 +1,12 @@
+use crate::algebra::linear::bivec::Bivector3;
 use crate::algebra::linear::mat::Matrix;
 use crate::algebra::linear::scalar::Scalar;
-use fructose::operators::{ClosedAdd, ClosedMul, ClosedSub};
+use fructose::algebra::lattice::Lattice;
+use fructose::operators::{ClosedAdd, ClosedDiv, ClosedMul, ClosedOps, ClosedSub};
+use fructose::properties::helpers::float::Float;
 use fructose::properties::helpers::identity::{One, Two};
+use fructose::properties::helpers::sign::Signed;
+use fructose::specific::complex::Real;
 use std::ops::{Index, IndexMut};
 
 pub type Point<T, const N: usize> = Vector<T, { N }>;
@@ -33,6 +38,81 @@ impl<T: Scalar + Copy + ClosedMul + ClosedAdd, const N: usize> Vector<T, { N }>
     }
 }
 
+impl<T: Scalar, const N: usize> Vector<T, { N }> {
+    /// appends `extra` to the end of the vector, `L` has to be `N + K`.
+    /// any other length fails to compile
+    #[inline]
+    pub fn extend<const K: usize, const L: usize>(
+        &self,
+        extra: Vector<T, { K }>,
+    ) -> Vector<T, { L }> {
+        const { assert!(L == N + K, "extended length has to be N + K") };
+        let mut vec = Vector::default();
+        vec.data[0][..N].copy_from_slice(&self.data[0]);
+        vec.data[0][N..].copy_from_slice(&extra.data[0]);
+        vec
+    }
+
+    /// keeps the first `K` components, `K` has to be at most `N`.
+    /// a larger `K` fails to compile
+    #[inline]
+    pub fn truncate<const K: usize>(&self) -> Vector<T, { K }> {
+        const { assert!(K <= N, "truncated length has to be at most N") };
+        let mut vec = Vector::default();
+        vec.data[0].copy_from_slice(&self.data[0][..K]);
+        vec
+    }
+}
+
+impl<T: Scalar + ClosedAdd, const N: usize> Vector<T, { N }> {
+    /// running total, element `i` is the sum of the first `i + 1` elements
+    #[inline]
+    pub fn cumsum(&self) -> Self {
+        let mut vec = *self;
+        for i in 1..N {
+            let previous = vec[i - 1];
+            vec[i] += previous;
+        }
+        vec
+    }
+}
+
+impl<T: Scalar + ClosedSub, const N: usize> Vector<T, { N }> {
+    /// consecutive differences `self[i + 1] - self[i]`, `K` has to be `N - 1`.
+    /// any other length fails to compile
+    #[inline]
+    pub fn diff<const K: usize>(&self) -> Vector<T, { K }> {
+        const { assert!(K + 1 == N, "difference length has to be N - 1") };
+        let mut vec = Vector::default();
+        for i in 0..K {
+            vec[i] = self[i + 1] - self[i];
+        }
+        vec
+    }
+}
+
+impl<T: Scalar + ClosedAdd + Signed + Lattice, const N: usize> Vector<T, { N }> {
+    /// sum of the absolute values (manhattan norm)
+    #[inline]
+    pub fn norm_l1(&self) -> T {
+        let mut sum = T::default();
+        for e in self.data[0].iter() {
+            sum += e.abs();
+        }
+        sum
+    }
+
+    /// largest absolute value (maximum norm), `0` for an empty vector
+    #[inline]
+    pub fn norm_linf(&self) -> T {
+        let mut max = T::default();
+        for e in self.data[0].iter() {
+            max = max.supremum(&e.abs());
+        }
+        max
+    }
+}
+
 impl<T: Scalar + One, const N: usize> Vector<T, { N }> {
     #[inline]
     pub fn unit(n: usize) -> Self {
@@ -49,6 +129,161 @@ impl<T: Scalar + Two + ClosedMul + ClosedAdd + ClosedSub, const N: usize> Vector
     }
 }
 
+impl<T: Scalar + ClosedOps + Real + Float, const N: usize> Vector<T, { N }> {
+    /// unit vector in the same direction, the zero vector if the magnitude is below epsilon
+    #[inline]
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalize_or(Self::default())
+    }
+
+    /// unit vector in the same direction, `fallback` if the magnitude is below epsilon
+    #[inline]
+    pub fn normalize_or(&self, fallback: Self) -> Self {
+        let magnitude = self.dot(*self).sqrt();
+        if magnitude < T::EPSILON {
+            return fallback;
+        }
+        let mut vec = *self;
+        vec /= magnitude;
+        vec
+    }
+
+    /// p-norm `(Σ |x_i|^p)^(1/p)` for `p >= 1`, `p = T::INFINITY` gives the maximum norm.
+    /// the elements are scaled by the largest absolute value first, so large components
+    /// don't overflow
+    pub fn norm_p(&self, p: T) -> T {
+        let max = self.norm_linf();
+        if p == T::INFINITY || max.is_zero() {
+            return max;
+        }
+        let mut sum = T::zero();
+        for e in self.data[0].iter() {
+            sum += (e.abs() / max).powf(p);
+        }
+        max * sum.powf(T::one() / p)
+    }
+
+    #[inline]
+    fn max_element(&self) -> T {
+        let mut max = T::NEG_INFINITY;
+        for e in self.data[0].iter() {
+            max = max.supremum(e);
+        }
+        max
+    }
+
+    /// `exp(x_i) / Σ exp(x_j)`, the largest element is subtracted before exponentiating
+    /// so large inputs don't overflow
+    pub fn softmax(&self) -> Self {
+        let max = self.max_element();
+        let mut vec = self.map(|e| (e - max).exp());
+        let mut sum = T::zero();
+        for e in vec.data[0].iter() {
+            sum += *e;
+        }
+        vec /= sum;
+        vec
+    }
+
+    /// `ln(Σ exp(x_i))` computed as `max + ln(Σ exp(x_i - max))` to avoid overflow,
+    /// negative infinity for an empty vector
+    pub fn log_sum_exp(&self) -> T {
+        let max = self.max_element();
+        if N == 0 {
+            return max;
+        }
+        let mut sum = T::zero();
+        for e in self.data[0].iter() {
+            sum += (*e - max).exp();
+        }
+        max + sum.ln()
+    }
+}
+
+impl<T: Scalar + ClosedMul + ClosedSub> Vector<T, 3> {
+    /// right handed cross product, orthogonal to both vectors
+    #[inline]
+    pub fn cross(&self, other: &Self) -> Self {
+        Vector::from([
+            self[1] * other[2] - self[2] * other[1],
+            self[2] * other[0] - self[0] * other[2],
+            self[0] * other[1] - self[1] * other[0],
+        ])
+    }
+
+    /// outer product `self ∧ other`, the oriented plane spanned by both vectors.
+    /// antisymmetric, and its dual `(yz, zx, xy)` is the cross product
+    #[inline]
+    pub fn wedge(&self, other: &Self) -> Bivector3<T> {
+        Bivector3::new(
+            self[0] * other[1] - self[1] * other[0],
+            self[1] * other[2] - self[2] * other[1],
+            self[2] * other[0] - self[0] * other[2],
+        )
+    }
+}
+
+impl<T: Scalar + ClosedOps + Real + Float> Vector<T, 3> {
+    /// direction of `self` refracted at a surface with snell's law, `eta` is the ratio of the
+    /// refractive indices `n_from / n_to`. `self` and `normal` are unit vectors, the normal
+    /// points against `self`. `None` on total internal reflection
+    pub fn refract(&self, normal: &Self, eta: T) -> Option<Self> {
+        let cos_incident = -self.dot(*normal);
+        let k = T::one() - eta * eta * (T::one() - cos_incident * cos_incident);
+        if k < T::zero() {
+            return None;
+        }
+        Some(*self * eta + *normal * (eta * cos_incident - k.sqrt()))
+    }
+}
+
+impl<T: Scalar + ClosedDiv + Real + Float> Vector<T, 4> {
+    /// divides x, y and z by w. if w is (almost) zero the xyz part is returned unchanged
+    #[inline]
+    pub fn perspective_divide(&self) -> Vector<T, 3> {
+        let xyz = self.truncate::<3>();
+        let w = self[3];
+        if w.abs() < T::EPSILON {
+            return xyz;
+        }
+        let mut vec = xyz;
+        vec /= w;
+        vec
+    }
+}
+
+/// whether the three points lie on a line, the determinant of `[b - a, c - a]`
+/// (twice the signed triangle area) is at most `epsilon` in magnitude
+pub fn are_collinear<T: Scalar + ClosedOps + Signed + Lattice>(
+    a: Vector<T, 2>,
+    b: Vector<T, 2>,
+    c: Vector<T, 2>,
+    epsilon: T,
+) -> bool {
+    let ab = b - a;
+    let ac = c - a;
+    let det = ab[0] * ac[1] - ab[1] * ac[0];
+    det.abs() <= epsilon
+}
+
+/// whether the four points lie in a plane, the scalar triple product of
+/// `b - a`, `c - a` and `d - a` (six times the signed tetrahedron volume) is at most
+/// `epsilon` in magnitude
+pub fn are_coplanar<T: Scalar + ClosedOps + Signed + Lattice>(
+    a: Vector<T, 3>,
+    b: Vector<T, 3>,
+    c: Vector<T, 3>,
+    d: Vector<T, 3>,
+    epsilon: T,
+) -> bool {
+    let ab = b - a;
+    let ac = c - a;
+    let ad = d - a;
+    let triple = ab[0] * (ac[1] * ad[2] - ac[2] * ad[1]) - ab[1] * (ac[0] * ad[2] - ac[2] * ad[0])
+        + ab[2] * (ac[0] * ad[1] - ac[1] * ad[0]);
+    triple.abs() <= epsilon
+}
+
 impl<T, const N: usize> From<[T; N]> for Vector<T, { N }> {
     fn from(rhs: [T; N]) -> Self {
         Point::new([rhs])
@@ -153,3 +388,191 @@ impl<T, const N: usize> IndexMut<usize> for Vector<T, { N }> {
 //         )+
 //     }
 // }
+
+#[cfg(test)]
+mod vec_tests {
+    use crate::algebra::linear::{are_collinear, are_coplanar, Bivector3, Vector};
+
+    #[test]
+    fn from_tuples() {
+        assert_eq!(Vector::from((1.0, 2.0)), Vector::from([1.0, 2.0]));
+        let vec = Vector::from((1.0, 2.0, 3.0));
+        assert_eq!((vec[0], vec[1], vec[2]), (1.0, 2.0, 3.0));
+        assert_eq!(Vector::from((1, 2, 3, 4)), Vector::from([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn abs_signum() {
+        let mut vec = Vector::from([-2.5, 0.0, 3.0, -0.5]);
+        assert_eq!(vec.abs_copy(), Vector::from([2.5, 0.0, 3.0, 0.5]));
+        assert_eq!(vec.signum(), Vector::from([-1.0, 1.0, 1.0, -1.0]));
+        vec.abs();
+        assert_eq!(vec, Vector::from([2.5, 0.0, 3.0, 0.5]));
+
+        assert_eq!(Vector::from([-7, 0, 4]).signum(), Vector::from([-1, 0, 1]));
+    }
+
+    #[test]
+    fn norms() {
+        let vec = Vector::from([3, -4]);
+        assert_eq!(vec.norm_l1(), 7);
+        assert_eq!(vec.norm_linf(), 4);
+
+        let vec = Vector::from([-1.5, 0.5, -0.25]);
+        assert_eq!(vec.norm_l1(), 2.25);
+        assert_eq!(vec.norm_linf(), 1.5);
+    }
+
+    #[test]
+    fn norm_p() {
+        let vec = Vector::<f64, 3>::from([3.0, -4.0, 1.5]);
+        assert!((vec.norm_p(2.0) - vec.dot(vec).sqrt()).abs() < 1e-12);
+        assert!((vec.norm_p(1.0) - vec.norm_l1()).abs() < 1e-12);
+        assert_eq!(vec.norm_p(f64::INFINITY), 4.0);
+        assert!((vec.norm_p(3.0) - 94.375f64.cbrt()).abs() < 1e-12);
+        assert_eq!(Vector::<f64, 3>::default().norm_p(2.0), 0.0);
+
+        // squaring 1e200 would overflow
+        let large = Vector::<f64, 2>::from([3e200, 4e200]);
+        assert!((large.norm_p(2.0) / 5e200 - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cross() {
+        let x = Vector::<i32, 3>::unit(0);
+        let y = Vector::unit(1);
+        let z = Vector::unit(2);
+        assert_eq!(x.cross(&y), z);
+        assert_eq!(y.cross(&z), x);
+        assert_eq!(z.cross(&x), y);
+        assert_eq!(y.cross(&x), -z);
+        assert_eq!(x.cross(&x), Vector::default());
+
+        let a = Vector::from([1, 2, 3]);
+        let b = Vector::from([4, 5, 6]);
+        assert_eq!(a.cross(&b), Vector::from([-3, 6, -3]));
+        assert_eq!(a.cross(&b).dot(a), 0);
+        assert_eq!(a.cross(&b).dot(b), 0);
+    }
+
+    #[test]
+    fn wedge() {
+        let x = Vector::<i32, 3>::unit(0);
+        let y = Vector::unit(1);
+        let z = Vector::unit(2);
+        assert_eq!(x.wedge(&y), Bivector3::new(1, 0, 0));
+        assert_eq!(y.wedge(&z), Bivector3::new(0, 1, 0));
+        assert_eq!(z.wedge(&x), Bivector3::new(0, 0, 1));
+
+        let a = Vector::from([1, 2, 3]);
+        let b = Vector::from([-4, 5, 0]);
+        assert_eq!(a.wedge(&b), -b.wedge(&a));
+        assert_eq!(a.wedge(&a), Bivector3::default());
+        let plane = a.wedge(&b);
+        assert_eq!(Vector::from([plane.yz, plane.zx, plane.xy]), a.cross(&b));
+    }
+
+    #[test]
+    fn cumsum_diff() {
+        let vec = Vector::<i32, 3>::from([1, 2, 3]);
+        assert_eq!(vec.cumsum(), Vector::from([1, 3, 6]));
+        assert_eq!(vec.diff(), Vector::from([1, 1]));
+        assert_eq!(vec.cumsum().diff::<2>(), Vector::from([2, 3]));
+        assert_eq!(Vector::<f64, 1>::from([2.5]).diff::<0>(), Vector::default());
+    }
+
+    #[test]
+    fn refract() {
+        // from air into glass at 45 degrees
+        let (sin, cos) = std::f64::consts::FRAC_PI_4.sin_cos();
+        let incident = Vector::<f64, 3>::from([sin, -cos, 0.0]);
+        let normal = Vector::from([0.0, 1.0, 0.0]);
+        let refracted = incident.refract(&normal, 1.0 / 1.5).unwrap();
+        let sin_refracted = sin / 1.5;
+        let cos_refracted = (1.0 - sin_refracted * sin_refracted).sqrt();
+        let expected = Vector::from([sin_refracted, -cos_refracted, 0.0]);
+        assert!((refracted - expected).norm_linf() < 1e-12);
+        assert!((refracted.dot(refracted) - 1.0).abs() < 1e-12);
+
+        // straight through at normal incidence
+        let down = Vector::from([0.0, -1.0, 0.0]);
+        assert_eq!(down.refract(&normal, 1.0 / 1.5), Some(down));
+
+        // from glass into air at 60 degrees, beyond the critical angle of ~41.8 degrees
+        let (sin, cos) = std::f64::consts::FRAC_PI_3.sin_cos();
+        let incident = Vector::<f64, 3>::from([sin, -cos, 0.0]);
+        assert_eq!(incident.refract(&normal, 1.5), None);
+    }
+
+    #[test]
+    fn softmax() {
+        let vec = Vector::<f64, 3>::from([1.0, 2.0, 3.0]);
+        let softmax = vec.softmax();
+        let sum: f64 = softmax.as_slice().iter().sum();
+        assert!((sum - 1.0).abs() < 1e-12);
+        let normalizer = 1f64.exp() + 2f64.exp() + 3f64.exp();
+        assert!((softmax[2] - 3f64.exp() / normalizer).abs() < 1e-12);
+        assert!((vec.log_sum_exp() - normalizer.ln()).abs() < 1e-12);
+
+        // exp(1000) overflows
+        let large = Vector::<f64, 3>::from([1000.0, 1000.0, 999.0]);
+        let softmax = large.softmax();
+        assert!(softmax.is_finite());
+        assert!((softmax[0] - softmax[1]).abs() < 1e-12);
+        let log_sum_exp = large.log_sum_exp();
+        assert!((log_sum_exp - (1000.0 + (2.0 + (-1f64).exp()).ln())).abs() < 1e-9);
+        assert_eq!(Vector::<f64, 0>::default().log_sum_exp(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn extend_truncate() {
+        let point = Vector::from([1.0, 2.0, 3.0]);
+        let homogeneous: Vector<f64, 4> = point.extend(Vector::from([1.0]));
+        assert_eq!(homogeneous, Vector::from([1.0, 2.0, 3.0, 1.0]));
+        assert_eq!(homogeneous.truncate::<3>(), point);
+    }
+
+    #[test]
+    fn perspective_divide() {
+        let vec = Vector::from([2.0, -4.0, 6.0, 2.0]);
+        assert_eq!(vec.perspective_divide(), Vector::from([1.0, -2.0, 3.0]));
+
+        let at_infinity = Vector::from([2.0, -4.0, 6.0, 0.0]);
+        assert_eq!(
+            at_infinity.perspective_divide(),
+            Vector::from([2.0, -4.0, 6.0])
+        );
+    }
+
+    #[test]
+    fn normalize_or() {
+        let zero = Vector::<f64, 3>::default();
+        assert_eq!(zero.normalize_or_zero(), zero);
+        let up = Vector::from([0.0, 1.0, 0.0]);
+        assert_eq!(zero.normalize_or(up), up);
+        assert_eq!(Vector::from([0.0, 1e-20, 0.0]).normalize_or(up), up);
+
+        let vec = Vector::from([3.0, 0.0, -4.0]);
+        assert_eq!(vec.normalize_or_zero(), Vector::from([0.6, 0.0, -0.8]));
+        assert_eq!(vec.normalize_or(up), Vector::from([0.6, 0.0, -0.8]));
+    }
+
+    #[test]
+    fn collinear() {
+        let a = Vector::from([0.0, 0.0]);
+        let b = Vector::from([1.0, 2.0]);
+        assert!(are_collinear(a, b, Vector::from([-2.0, -4.0]), 1e-12));
+        assert!(are_collinear(a, b, b, 1e-12));
+        assert!(!are_collinear(a, b, Vector::from([2.0, 3.0]), 1e-12));
+        assert!(are_collinear(a, b, Vector::from([2.0, 4.001]), 1e-2));
+    }
+
+    #[test]
+    fn coplanar() {
+        let a = Vector::from([0, 0, 1]);
+        let b = Vector::from([1, 0, 1]);
+        let c = Vector::from([0, 1, 1]);
+        assert!(are_coplanar(a, b, c, Vector::from([5, -3, 1]), 0));
+        assert!(!are_coplanar(a, b, c, Vector::from([5, -3, 2]), 0));
+    }
+}
@@ -3,3 +3,15 @@ pub mod bytemuck;
 
 #[cfg(feature = "mint")]
 pub mod mint;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "rand")]
+pub mod rand;
+
+#[cfg(feature = "ron")]
+pub mod ron;
+
+#[cfg(feature = "serde")]
+pub mod serde;
@@ -0,0 +1,66 @@
+#[cfg(feature = "algebra")]
+pub mod algebra {
+    use crate::algebra::linear::{Matrix, Scalar};
+    use proptest::arbitrary::Arbitrary;
+    use proptest::collection::vec;
+    use proptest::strategy::{BoxedStrategy, Strategy};
+
+    impl<T: Scalar + std::fmt::Debug, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+        /// strategy generating matrices with every element drawn from `element`
+        pub fn strategy<S: Strategy<Value = T>>(element: S) -> impl Strategy<Value = Self> {
+            vec(element, M * N).prop_map(|elements| {
+                let mut mat = Self::default();
+                mat.as_slice_mut().copy_from_slice(&elements);
+                mat
+            })
+        }
+    }
+
+    // elements are bounded so that sums and products of a few generated values stay finite
+    macro_rules! impl_arbitrary {
+        ($($t:ty => $bound:expr),* $(,)?) => {
+            $(
+                impl<const M: usize, const N: usize> Arbitrary for Matrix<$t, { M }, { N }> {
+                    type Parameters = ();
+                    type Strategy = BoxedStrategy<Self>;
+
+                    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                        Self::strategy(-$bound..=$bound).boxed()
+                    }
+                }
+            )*
+        }
+    }
+
+    impl_arbitrary! {
+        i8 => 10_i8,
+        i16 => 100_i16,
+        i32 => 1_000_i32,
+        i64 => 1_000_000_i64,
+        f32 => 1e3_f32,
+        f64 => 1e6_f64,
+    }
+
+    #[cfg(test)]
+    mod proptest_tests {
+        use crate::algebra::linear::{Matrix, Vector};
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn add_sub_roundtrip(a: Matrix<f64, 3, 4>, b: Matrix<f64, 3, 4>) {
+                let c = (a + b) - b;
+                for m in 0..3 {
+                    for n in 0..4 {
+                        prop_assert!((c[[m, n]] - a[[m, n]]).abs() <= 1e-9 * b[[m, n]].abs().max(1.0));
+                    }
+                }
+            }
+
+            #[test]
+            fn add_commutative(a: Vector<i32, 5>, b: Vector<i32, 5>) {
+                prop_assert_eq!(a + b, b + a);
+            }
+        }
+    }
+}
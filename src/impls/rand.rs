@@ -0,0 +1,80 @@
+#[cfg(feature = "algebra")]
+pub mod algebra {
+    use crate::algebra::linear::{Matrix, Scalar, SquareMatrix};
+    use fructose::operators::ClosedOps;
+    use fructose::specific::complex::Real;
+    use rand::distributions::{Distribution, Standard};
+    use rand::Rng;
+    use rand_distr::StandardNormal;
+
+    impl<T: Scalar, const M: usize, const N: usize> Matrix<T, { M }, { N }>
+    where
+        Standard: Distribution<T>,
+    {
+        /// fills every element from the `Standard` distribution
+        pub fn random(rng: &mut impl Rng) -> Self {
+            let mut mat = Self::default();
+            mat.as_slice_mut().iter_mut().for_each(|e| *e = rng.gen());
+            mat
+        }
+    }
+
+    impl<T: Scalar + ClosedOps + Real, const N: usize> SquareMatrix<T, { N }>
+    where
+        StandardNormal: Distribution<T>,
+    {
+        /// uniformly distributed (haar measure) orthogonal matrix, computed from the QR
+        /// decomposition of a matrix with standard normal distributed elements
+        pub fn random_orthogonal(rng: &mut impl Rng) -> Self {
+            let mut gaussian = Self::default();
+            gaussian
+                .as_slice_mut()
+                .iter_mut()
+                .for_each(|e| *e = rng.sample(StandardNormal));
+
+            let (mut q, r) = gaussian.qr();
+            // fixing the signs of R's diagonal makes the distribution uniform
+            for n in 0..N {
+                if r[[n, n]].is_negative() {
+                    for m in 0..N {
+                        q[[m, n]] = -q[[m, n]];
+                    }
+                }
+            }
+            q
+        }
+    }
+
+    #[cfg(test)]
+    mod rand_tests {
+        use crate::algebra::linear::{SquareMatrix, Vector};
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        #[test]
+        fn random() {
+            let mut rng = StdRng::seed_from_u64(42);
+            let vec = Vector::<f64, 4>::random(&mut rng);
+            for i in 0..4 {
+                assert!(vec[i] >= 0.0 && vec[i] < 1.0);
+            }
+            assert_ne!(vec, Vector::<f64, 4>::random(&mut rng));
+        }
+
+        #[test]
+        fn random_orthogonal() {
+            let mut rng = StdRng::seed_from_u64(7);
+            for _ in 0..10 {
+                let mat = SquareMatrix::<f64, 3>::random_orthogonal(&mut rng);
+                let gram = mat.gram();
+                for m in 0..3 {
+                    for n in 0..3 {
+                        let expected = if m == n { 1.0 } else { 0.0 };
+                        assert!((gram[[m, n]] - expected).abs() < 1e-12);
+                    }
+                }
+                assert!((mat.determinant().abs() - 1.0).abs() < 1e-12);
+            }
+        }
+    }
+}
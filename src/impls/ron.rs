@@ -0,0 +1,51 @@
+#[cfg(feature = "algebra")]
+pub mod algebra {
+    use crate::algebra::linear::{Matrix, Scalar};
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+
+    impl<T: Scalar + Serialize, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+        /// the `Serialize` format, the size followed by the column major elements
+        pub fn to_ron(&self) -> String {
+            ron::to_string(self).expect("failed to serialize matrix")
+        }
+    }
+
+    impl<T: Scalar + DeserializeOwned, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+        /// parses the format written by `to_ron` (or `ron::to_string`)
+        pub fn from_ron(ron: &str) -> ron::Result<Self> {
+            ron::from_str(ron)
+        }
+    }
+
+    #[cfg(test)]
+    mod ron_tests {
+        use crate::algebra::linear::{Matrix, SquareMatrix, Vector};
+
+        #[test]
+        fn round_trip() {
+            let mat =
+                SquareMatrix::<f64, 3>::new([[1.0, -2.5, 0.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.5]]);
+            let ron = mat.to_ron();
+            assert_eq!(
+                ron,
+                "(rows:3,cols:3,data:[1.0,-2.5,0.0,4.0,5.0,6.0,7.0,8.0,9.5])"
+            );
+            assert_eq!(ron, ron::to_string(&mat).unwrap());
+            assert_eq!(SquareMatrix::from_ron(&ron).unwrap(), mat);
+            assert_eq!(ron::from_str::<SquareMatrix<f64, 3>>(&ron).unwrap(), mat);
+
+            let vec = Vector::<i32, 3>::from([1, 2, 3]);
+            assert_eq!(Vector::from_ron(&vec.to_ron()).unwrap(), vec);
+        }
+
+        #[test]
+        fn wrong_size() {
+            assert!(
+                Matrix::<i32, 2, 2>::from_ron("(rows: 2, cols: 3, data: [1, 2, 3, 4, 5, 6])")
+                    .is_err()
+            );
+            assert!(Matrix::<i32, 2, 2>::from_ron("(rows: 2, cols: 2, data: [1, 2, 3])").is_err());
+        }
+    }
+}
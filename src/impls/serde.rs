@@ -1,84 +1,326 @@
-use crate::numeric::num::Num;
-use crate::{Matrix, Vector};
-use serde::de::{Error, SeqAccess, Visitor};
-use serde::ser::SerializeSeq;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::fmt::Formatter;
-use std::marker::PhantomData;
-
-impl<T: Num + Serialize, const N: usize> Serialize for Vector<T, { N }> {
-    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+#[cfg(feature = "algebra")]
+pub mod algebra {
+    use crate::algebra::linear::{DMatrix, DVector, Matrix, Scalar, SparseVector};
+    use serde::de::Error;
+    use serde::ser::{SerializeSeq, SerializeStruct};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// serializes the `len` elements of an iterator as a sequence
+    struct Flat<I> {
+        elements: I,
+        len: usize,
+    }
+
+    impl<I: Iterator + Clone> Serialize for Flat<I>
     where
-        S: Serializer,
+        I::Item: Serialize,
     {
-        let mut seq = serializer.serialize_seq(Some(N))?;
-        for e in &self.data {
-            seq.serialize_element(e)?;
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut seq = serializer.serialize_seq(Some(self.len))?;
+            for e in self.elements.clone() {
+                seq.serialize_element(&e)?;
+            }
+            seq.end()
         }
-        seq.end()
     }
-}
 
-struct VectorVisitor<T, const N: usize> {
-    marker: PhantomData<T>,
-}
+    /// static and dynamic matrices are both stored as their size and the column major elements
+    fn serialize_matrix<'a, S, T, I>(
+        serializer: S,
+        size: (usize, usize),
+        elements: I,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize + 'a,
+        I: Iterator<Item = &'a T> + Clone,
+    {
+        let mut state = serializer.serialize_struct("Matrix", 3)?;
+        state.serialize_field("rows", &size.0)?;
+        state.serialize_field("cols", &size.1)?;
+        let len = size.0 * size.1;
+        state.serialize_field("data", &Flat { elements, len })?;
+        state.end()
+    }
 
-impl<T, const N: usize> VectorVisitor<T, { N }> {
-    pub fn new() -> Self {
-        Self {
-            marker: PhantomData,
+    #[derive(Deserialize)]
+    #[serde(rename = "Matrix")]
+    struct MatrixData<T> {
+        rows: usize,
+        cols: usize,
+        data: Vec<T>,
+    }
+
+    impl<T> MatrixData<T> {
+        fn check<E: Error>(&self) -> Result<(), E> {
+            let len = self.rows.checked_mul(self.cols).ok_or_else(|| {
+                E::custom(format!(
+                    "a {}x{} matrix has too many elements",
+                    self.rows, self.cols
+                ))
+            })?;
+            if self.data.len() != len {
+                return Err(E::invalid_length(
+                    self.data.len(),
+                    &format!("{} elements", len).as_str(),
+                ));
+            }
+            Ok(())
         }
     }
-}
-impl<'de, T: Num + Default + Deserialize<'de> + Copy, const N: usize> Deserialize<'de>
-    for Vector<T, { N }>
-{
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
+
+    impl<T: Serialize, const M: usize, const N: usize> Serialize for Matrix<T, { M }, { N }> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_matrix(serializer, (M, N), self.data.iter().flatten())
+        }
+    }
+
+    impl<'de, T: Scalar + Deserialize<'de>, const M: usize, const N: usize> Deserialize<'de>
+        for Matrix<T, { M }, { N }>
     {
-        deserializer.deserialize_seq(VectorVisitor::new())
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let data = MatrixData::<T>::deserialize(deserializer)?;
+            if (data.rows, data.cols) != (M, N) {
+                return Err(D::Error::custom(format!(
+                    "expected a {}x{} matrix, found {}x{}",
+                    M, N, data.rows, data.cols
+                )));
+            }
+            data.check()?;
+            let mut mat = Self::default();
+            mat.as_slice_mut().copy_from_slice(&data.data);
+            Ok(mat)
+        }
     }
-}
 
-impl<'de, T: Num + Default + Deserialize<'de> + Copy, const N: usize> Visitor<'de>
-    for VectorVisitor<T, { N }>
-{
-    type Value = Vector<T, { N }>;
+    impl<T: Serialize> Serialize for DMatrix<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_matrix(serializer, self.size, self.data.iter().flatten())
+        }
+    }
 
-    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        formatter.write_str("vector")
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for DMatrix<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let data = MatrixData::<T>::deserialize(deserializer)?;
+            data.check()?;
+            let MatrixData { rows, cols, data } = data;
+            let mut elements = data.into_iter();
+            let columns = (0..cols)
+                .map(|_| elements.by_ref().take(rows).collect())
+                .collect();
+            Ok(DMatrix {
+                data: columns,
+                size: (rows, cols),
+            })
+        }
     }
 
-    fn visit_seq<V>(self, mut seq: V) -> Result<Vector<T, { N }>, V::Error>
-    where
-        V: SeqAccess<'de>,
-    {
-        let mut vec = Vector::default();
-        for i in 0..N {
-            vec[i] = seq.next_element()?.unwrap();
+    /// vectors are stored as the sequence of their elements
+    impl<T: Serialize> Serialize for DVector<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.data.serialize(serializer)
         }
-        Ok(vec)
     }
-}
 
-#[cfg(test)]
-mod serde_tests {
-    use crate::Vector;
-    use serde::{Deserialize, Serialize};
-    use serde_test::{assert_tokens, Token};
-
-    #[test]
-    fn vec_serialize_deserialize() {
-        let vec = Vector::new([0.1, 0.4, -2.0]);
-        assert_tokens(
-            &vec,
-            &[
-                Token::Seq { len: Some(3) },
-                Token::F64(0.1),
-                Token::F64(0.4),
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for DVector<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Ok(DVector::new(Vec::deserialize(deserializer)?))
+        }
+    }
+
+    /// sparse vectors are stored as their sorted `(index, value)` pairs and length
+    impl<T: Serialize> Serialize for SparseVector<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("SparseVector", 2)?;
+            state.serialize_field("entries", self.entries())?;
+            state.serialize_field("len", &self.len())?;
+            state.end()
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "SparseVector")]
+    struct SparseVectorData<T> {
+        entries: Vec<(usize, T)>,
+        len: usize,
+    }
+
+    /// deserialized entries go through the same checks as `SparseVector::new`
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for SparseVector<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let data = SparseVectorData::<T>::deserialize(deserializer)?;
+            SparseVector::validated(data.len, data.entries).map_err(D::Error::custom)
+        }
+    }
+
+    /// `DMatrix` and `DVector` don't implement `PartialEq`, so they are round-tripped through json
+    #[cfg(test)]
+    mod serde_tests {
+        use crate::algebra::linear::{DMatrix, DVector, Matrix, SparseVector, Vector};
+        use serde_test::{
+            assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Token,
+        };
+
+        fn matrix_tokens(rows: u64, cols: u64, data: &[f64]) -> Vec<Token> {
+            let mut tokens = vec![
+                Token::Struct {
+                    name: "Matrix",
+                    len: 3,
+                },
+                Token::Str("rows"),
+                Token::U64(rows),
+                Token::Str("cols"),
+                Token::U64(cols),
+                Token::Str("data"),
+                Token::Seq {
+                    len: Some(data.len()),
+                },
+            ];
+            tokens.extend(data.iter().map(|e| Token::F64(*e)));
+            tokens.extend([Token::SeqEnd, Token::StructEnd]);
+            tokens
+        }
+
+        #[test]
+        fn matrix() {
+            // column major
+            let mat = Matrix::<f64, 2, 3>::new([[1.0, 2.0], [3.0, 4.0], [5.0, -6.5]]);
+            let tokens = matrix_tokens(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, -6.5]);
+            assert_tokens(&mat, &tokens);
+
+            let vec = Vector::<f64, 3>::from([0.1, 0.4, -2.0]);
+            assert_tokens(&vec, &matrix_tokens(3, 1, &[0.1, 0.4, -2.0]));
+        }
+
+        #[test]
+        fn matrix_wrong_size() {
+            let tokens = matrix_tokens(3, 2, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+            assert_de_tokens_error::<Matrix<f64, 2, 3>>(
+                &tokens,
+                "expected a 2x3 matrix, found 3x2",
+            );
+            let tokens = matrix_tokens(2, 2, &[1.0, 2.0, 3.0]);
+            assert_de_tokens_error::<Matrix<f64, 2, 2>>(
+                &tokens,
+                "invalid length 3, expected 4 elements",
+            );
+        }
+
+        #[test]
+        fn dynamic() {
+            let mat = DMatrix::<f64>::from("1 2;3 4;5 -6.5");
+            let tokens = matrix_tokens(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, -6.5]);
+            assert_ser_tokens(&mat, &tokens);
+            let json = serde_json::to_string(&mat).unwrap();
+            assert_eq!(
+                json,
+                r#"{"rows":2,"cols":3,"data":[1.0,2.0,3.0,4.0,5.0,-6.5]}"#
+            );
+            let de: DMatrix<f64> = serde_json::from_str(&json).unwrap();
+            assert_eq!(de.size, mat.size);
+            assert_eq!(de.data, mat.data);
+
+            let tokens = matrix_tokens(2, 2, &[1.0, 2.0, 3.0]);
+            assert_de_tokens_error::<DMatrix<f64>>(
+                &tokens,
+                "invalid length 3, expected 4 elements",
+            );
+
+            // the number of elements overflows, a wrapping product would accept the empty data
+            let tokens = matrix_tokens(1 << 63, 2, &[]);
+            assert_de_tokens_error::<DMatrix<f64>>(
+                &tokens,
+                &format!("a {}x2 matrix has too many elements", 1u64 << 63),
+            );
+            assert_de_tokens_error::<Matrix<f64, 2, 2>>(
+                &matrix_tokens(1 << 63, 2, &[]),
+                "expected a 2x2 matrix, found 9223372036854775808x2",
+            );
+
+            let vec = DVector::new(vec![1.0, -2.0]);
+            let tokens = [
+                Token::Seq { len: Some(2) },
+                Token::F64(1.0),
                 Token::F64(-2.0),
-            ],
-        );
+                Token::SeqEnd,
+            ];
+            assert_ser_tokens(&vec, &tokens);
+            let de: DVector<f64> =
+                serde_json::from_str(&serde_json::to_string(&vec).unwrap()).unwrap();
+            assert_eq!((de.data, de.len), (vec.data, 2));
+        }
+
+        #[test]
+        fn sparse() {
+            let sparse = SparseVector::new(5, vec![(3, -2.5), (1, 4.0)]);
+            assert_tokens(
+                &sparse,
+                &[
+                    Token::Struct {
+                        name: "SparseVector",
+                        len: 2,
+                    },
+                    Token::Str("entries"),
+                    Token::Seq { len: Some(2) },
+                    Token::Tuple { len: 2 },
+                    Token::U64(1),
+                    Token::F64(4.0),
+                    Token::TupleEnd,
+                    Token::Tuple { len: 2 },
+                    Token::U64(3),
+                    Token::F64(-2.5),
+                    Token::TupleEnd,
+                    Token::SeqEnd,
+                    Token::Str("len"),
+                    Token::U64(5),
+                    Token::StructEnd,
+                ],
+            );
+        }
+
+        #[test]
+        fn sparse_invalid() {
+            let tokens = |entries: &[(u64, f64)], len: u64| {
+                let mut tokens = vec![
+                    Token::Struct {
+                        name: "SparseVector",
+                        len: 2,
+                    },
+                    Token::Str("entries"),
+                    Token::Seq {
+                        len: Some(entries.len()),
+                    },
+                ];
+                for &(index, e) in entries {
+                    tokens.extend([
+                        Token::Tuple { len: 2 },
+                        Token::U64(index),
+                        Token::F64(e),
+                        Token::TupleEnd,
+                    ]);
+                }
+                tokens.extend([
+                    Token::SeqEnd,
+                    Token::Str("len"),
+                    Token::U64(len),
+                    Token::StructEnd,
+                ]);
+                tokens
+            };
+
+            assert_de_tokens_error::<SparseVector<f64>>(
+                &tokens(&[(1, 4.0), (3, -2.5)], 3),
+                "index 3 out of bounds for length 3",
+            );
+            assert_de_tokens_error::<SparseVector<f64>>(
+                &tokens(&[(1, 4.0), (1, -2.5)], 3),
+                "index 1 is repeated",
+            );
+            // unsorted entries are sorted like in `new`
+            assert_de_tokens(
+                &SparseVector::new(5, vec![(1, 4.0), (3, -2.5)]),
+                &tokens(&[(3, -2.5), (1, 4.0)], 5),
+            );
+        }
     }
 }
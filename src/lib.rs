@@ -1,5 +1,6 @@
 #![allow(unused)]
 #![deny(unused_imports)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 
 #[cfg(feature = "algebra")]
 pub mod algebra;